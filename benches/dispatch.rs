@@ -0,0 +1,152 @@
+//! Dispatch overhead of the `HashMap` route table (`router_builder!`)
+//! versus the compile-time perfect hash route table (`static_router_builder!`).
+//!
+//! Run with `cargo bench --bench dispatch`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rpc_router::{HandlerResult, Router, router_builder, static_router_builder};
+use std::hint::black_box;
+
+// region:    --- Bench Assets
+
+macro_rules! handlers {
+	($($name:ident),+ $(,)?) => {
+		$(
+			pub async fn $name() -> HandlerResult<u32> {
+				Ok(1)
+			}
+		)+
+	};
+}
+
+handlers!(
+	get_project,
+	list_projects,
+	create_project,
+	update_project,
+	delete_project,
+	archive_project,
+	get_task,
+	list_tasks,
+	create_task,
+	update_task,
+	delete_task,
+	move_task,
+	get_user,
+	list_users,
+	create_user,
+	update_user,
+	delete_user,
+	get_team,
+	list_teams,
+	create_team,
+	update_team,
+	delete_team,
+	get_comment,
+	list_comments,
+	create_comment,
+	update_comment,
+	delete_comment,
+	get_label,
+	list_labels,
+	create_label,
+	update_label,
+	delete_label,
+);
+
+fn hashmap_router() -> Router {
+	router_builder!(
+		get_project,
+		list_projects,
+		create_project,
+		update_project,
+		delete_project,
+		archive_project,
+		get_task,
+		list_tasks,
+		create_task,
+		update_task,
+		delete_task,
+		move_task,
+		get_user,
+		list_users,
+		create_user,
+		update_user,
+		delete_user,
+		get_team,
+		list_teams,
+		create_team,
+		update_team,
+		delete_team,
+		get_comment,
+		list_comments,
+		create_comment,
+		update_comment,
+		delete_comment,
+		get_label,
+		list_labels,
+		create_label,
+		update_label,
+		delete_label,
+	)
+	.build()
+}
+
+fn static_router() -> Router {
+	static_router_builder!(
+		get_project,
+		list_projects,
+		create_project,
+		update_project,
+		delete_project,
+		archive_project,
+		get_task,
+		list_tasks,
+		create_task,
+		update_task,
+		delete_task,
+		move_task,
+		get_user,
+		list_users,
+		create_user,
+		update_user,
+		delete_user,
+		get_team,
+		list_teams,
+		create_team,
+		update_team,
+		delete_team,
+		get_comment,
+		list_comments,
+		create_comment,
+		update_comment,
+		delete_comment,
+		get_label,
+		list_labels,
+		create_label,
+		update_label,
+		delete_label,
+	)
+	.build()
+}
+
+// endregion: --- Bench Assets
+
+fn bench_dispatch(c: &mut Criterion) {
+	let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+	let routers = [("hashmap", hashmap_router()), ("static", static_router())];
+
+	let mut group = c.benchmark_group("dispatch");
+	for (name, router) in routers {
+		for method in ["get_project", "delete_label", "unknown_method"] {
+			group.bench_function(format!("{name}/{method}"), |b| {
+				b.to_async(&runtime)
+					.iter(|| async { black_box(router.call_route(None, black_box(method), None).await) })
+			});
+		}
+	}
+	group.finish();
+}
+
+criterion_group!(benches, bench_dispatch);
+criterion_main!(benches);
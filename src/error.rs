@@ -1,6 +1,7 @@
-use crate::{FromResourcesError, HandlerError};
+use crate::{FromResourcesError, GuardRejection, HandlerError};
 use serde::Serialize;
-use serde_with::{DisplayFromStr, serde_as};
+use serde_with::{DisplayFromStr, DurationMilliSeconds, serde_as};
+use std::time::Duration;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -13,6 +14,14 @@ pub enum Error {
 
 	// -- Router
 	MethodUnknown,
+	/// The call did not complete within this timeout (see `RouterBuilder::timeout`).
+	Timeout(#[serde_as(as = "DurationMilliSeconds<u64>")] Duration),
+	/// The method already has this maximum number of running calls (see `RouterBuilder::method_concurrency_limit`).
+	ConcurrencyLimitReached(usize),
+	/// The call exceeded its `RateLimit`, and can be retried after this duration.
+	RateLimited(#[serde_as(as = "DurationMilliSeconds<u64>")] Duration),
+	/// The call was rejected by a `Guard` (before the params parsing and handler execution).
+	GuardRejected(GuardRejection),
 
 	// -- Handler
 	FromResources(FromResourcesError),
@@ -20,6 +29,24 @@ pub enum Error {
 	Handler(#[serde_as(as = "DisplayFromStr")] HandlerError),
 }
 
+impl Error {
+	/// Returns the name of the error variant (e.g., `"MethodUnknown"`), for metrics and logs.
+	pub fn kind(&self) -> &'static str {
+		match self {
+			Error::ParamsParsing(_) => "ParamsParsing",
+			Error::ParamsMissingButRequested => "ParamsMissingButRequested",
+			Error::MethodUnknown => "MethodUnknown",
+			Error::Timeout(_) => "Timeout",
+			Error::ConcurrencyLimitReached(_) => "ConcurrencyLimitReached",
+			Error::RateLimited(_) => "RateLimited",
+			Error::GuardRejected(_) => "GuardRejected",
+			Error::FromResources(_) => "FromResources",
+			Error::HandlerResultSerialize(_) => "HandlerResultSerialize",
+			Error::Handler(_) => "Handler",
+		}
+	}
+}
+
 // region:    --- Froms
 
 impl From<HandlerError> for Error {
@@ -28,6 +55,12 @@ impl From<HandlerError> for Error {
 	}
 }
 
+impl From<GuardRejection> for Error {
+	fn from(val: GuardRejection) -> Self {
+		Self::GuardRejected(val)
+	}
+}
+
 impl From<FromResourcesError> for Error {
 	fn from(val: FromResourcesError) -> Self {
 		Self::FromResources(val)
@@ -10,9 +10,8 @@ use serde_json::Value;
 /// - The call format is normalized to two `impl FromResources` arguments (for now) and one optionals  `impl IntoParams`, which represent the json-rpc's optional value.
 /// - `into_box` is a convenient method for converting a RpcHandler into a Boxed dyn RpcHandlerWrapperTrait,
 ///   allowing for dynamic dispatch by the Router.
-/// - A `RpcHandler` will typically be implemented for static functions, as `FnOnce`,
-///   enabling them to be cloned with none or negligible performance impact,
-///   thus facilitating the use of RpcRoute dynamic dispatch.
+/// - A `RpcHandler` will typically be implemented for static functions (or closures), as `Fn`,
+///   called by reference, so the handler is not cloned per call.
 /// - `T` is the tuple of `impl FromResources` arguments.
 /// - `P` is the `impl IntoParams` argument.
 ///
@@ -26,7 +25,7 @@ where
 	type Future: Future<Output = Result<Value>> + Send + 'static;
 
 	/// Call the handler.
-	fn call(self, rpc_resources: Resources, params: Option<Value>) -> Self::Future;
+	fn call(&self, rpc_resources: Resources, params: Option<Value>) -> Self::Future;
 
 	/// Convert this RpcHandler into a Boxed dyn RpcHandlerWrapperTrait,
 	/// for dynamic dispatch by the Router.
@@ -41,10 +41,9 @@ where
 	R: Send + Sync + 'static,
 {
 	pub fn call(&self, rpc_resources: Resources, params: Option<Value>) -> H::Future {
-		// Note: Since handler is a FnOnce, we can use it only once, so we clone it.
-		//       This is likely optimized by the compiler.
-		let handler = self.handler.clone();
-		Handler::call(handler, rpc_resources, params)
+		// Note: The handler is called by reference (the wrapper is shared behind the `Arc` of the route),
+		//       so it is not cloned per call, whatever its captures.
+		Handler::call(&self.handler, rpc_resources, params)
 	}
 }
 
@@ -9,28 +9,34 @@ macro_rules! impl_handler_pair {
 		// Handler implementations for zero or more FromResources with the last argument being IntoParams
         impl<F, Fut, $($T,)* P, R, E> $crate::Handler<($($T,)*), (P,), R> for F
         where
-            F: FnOnce($($T,)* P) -> Fut + Clone + Send + 'static,
+            F: Fn($($T,)* P) -> Fut + Clone + Send + Sync + 'static,
             $( $T: $crate::FromResources+ Clone + Send + Sync + 'static, )*
             P: $crate::IntoParams + Send + Sync + 'static,
             R: serde::Serialize + Send + Sync + 'static,
             E: $crate::IntoHandlerError,
-            Fut: futures::Future<Output = core::result::Result<R, E>> + Send,
+            Fut: futures::Future<Output = core::result::Result<R, E>> + Send + 'static,
         {
             type Future = $crate::handler::PinFutureValue;
 
 			#[allow(unused)] // somehow resources will be marked as unused
             fn call(
-                self,
+                &self,
                 resources: Resources,
                 params_value: Option<serde_json::Value>,
             ) -> Self::Future {
-                Box::pin(async move {
+                // Note: The handler future is created by reference (no handler clone per call),
+                //       and only the eventual params or resources error is carried to the returned future.
+                let new_fut = || -> $crate::Result<Fut> {
                     let param = P::into_params(params_value)?;
-
-                    let res = self(
+                    Ok(self(
                         $( $T::from_resources(&resources)?, )*
                         param,
-                    ).await;
+                    ))
+                };
+                let fut = new_fut();
+
+                Box::pin(async move {
+                    let res = fut?.await;
 
                     match res {
                         Ok(result) => Ok(serde_json::to_value(result).map_err($crate::Error::HandlerResultSerialize)?),
@@ -46,24 +52,29 @@ macro_rules! impl_handler_pair {
        // Handler implementations for zero or more FromResources and NO IntoParams
        impl<F, Fut, $($T,)* R, E> $crate::Handler<($($T,)*), (), R> for F
        where
-               F: FnOnce($($T,)*) -> Fut + Clone + Send + 'static,
+               F: Fn($($T,)*) -> Fut + Clone + Send + Sync + 'static,
                $( $T: $crate::FromResources + Clone + Send + Sync + 'static, )*
                R: serde::Serialize + Send + Sync + 'static,
                E: $crate::IntoHandlerError,
-               Fut: futures::Future<Output = core::result::Result<R, E>> + Send,
+               Fut: futures::Future<Output = core::result::Result<R, E>> + Send + 'static,
        {
                type Future = $crate::handler::PinFutureValue;
 
                #[allow(unused)] // somehow resources will be marked as unused
                fn call(
-                       self,
+                       &self,
                        resources: Resources,
                        _params: Option<serde_json::Value>,
                ) -> Self::Future {
-                       Box::pin(async move {
-                            let res = self(
+                       let new_fut = || -> $crate::Result<Fut> {
+                            Ok(self(
                                     $( $T::from_resources(&resources)?, )*
-                            ).await;
+                            ))
+                       };
+                       let fut = new_fut();
+
+                       Box::pin(async move {
+                            let res = fut?.await;
 
                             match res {
                                 Ok(result) => Ok(serde_json::to_value(result).map_err($crate::Error::HandlerResultSerialize)?),
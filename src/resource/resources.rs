@@ -1,4 +1,6 @@
 use super::resources_inner::ResourcesInner;
+use crate::{CallContext, FallbackMethod};
+use std::any::Any;
 use std::sync::Arc;
 
 // region:    --- Builder
@@ -48,6 +50,10 @@ impl ResourcesBuilder {
 pub struct Resources {
 	base_inner: Arc<ResourcesInner>,
 	overlay_inner: Arc<ResourcesInner>,
+	/// The context of the current call (set by the router for each dispatch, see `CallContext`).
+	call_context: Option<CallContext>,
+	/// The method name of the call dispatched to the fallback handler (see `FallbackMethod`).
+	fallback_method: Option<FallbackMethod>,
 }
 
 // -- Builder
@@ -62,6 +68,20 @@ impl Resources {
 // -- Public Methods
 impl Resources {
 	pub fn get<T: Clone + Send + Sync + 'static>(&self) -> Option<T> {
+		// Note: The call context and fallback method are stored inline, so they are checked first
+		//       (the downcasts are `TypeId` comparisons).
+		let call_context = self.call_context.as_ref().and_then(|cc| (cc as &dyn Any).downcast_ref::<T>());
+		if let Some(call_context) = call_context {
+			return Some(call_context.clone());
+		}
+		let fallback_method = self
+			.fallback_method
+			.as_ref()
+			.and_then(|fm| (fm as &dyn Any).downcast_ref::<T>());
+		if let Some(fallback_method) = fallback_method {
+			return Some(fallback_method.clone());
+		}
+
 		// first additional, then base
 		self.overlay_inner.get::<T>().or_else(|| self.base_inner.get::<T>()).cloned()
 	}
@@ -79,6 +99,8 @@ impl Resources {
 		Self {
 			base_inner: Arc::new(base_inner),
 			overlay_inner: Default::default(),
+			call_context: None,
+			fallback_method: None,
 		}
 	}
 
@@ -86,8 +108,39 @@ impl Resources {
 		Self {
 			base_inner: self.base_inner.clone(),
 			overlay_inner: overlay_resources.base_inner.clone(),
+			call_context: self.call_context.clone(),
+			fallback_method: self.fallback_method.clone(),
+		}
+	}
+
+	/// Returns a copy of the base resources (cheap, as the resource values are typically `Arc`s).
+	pub(crate) fn to_base_inner(&self) -> ResourcesInner {
+		(*self.base_inner).clone()
+	}
+
+	/// Returns new resources with `val` added to a copy of the overlay (replacing the eventual value of the same type).
+	pub(crate) fn new_with_overlay_resource<T: Clone + Send + Sync + 'static>(&self, val: T) -> Self {
+		let mut overlay_inner = (*self.overlay_inner).clone();
+		overlay_inner.insert(val);
+		Self {
+			base_inner: self.base_inner.clone(),
+			overlay_inner: Arc::new(overlay_inner),
+			call_context: self.call_context.clone(),
+			fallback_method: self.fallback_method.clone(),
 		}
 	}
+
+	/// Returns these resources with the context of the call.
+	pub(crate) fn with_call_context(mut self, call_context: CallContext) -> Self {
+		self.call_context = Some(call_context);
+		self
+	}
+
+	/// Returns these resources with the method name of the call dispatched to the fallback handler.
+	pub(crate) fn with_fallback_method(mut self, fallback_method: FallbackMethod) -> Self {
+		self.fallback_method = Some(fallback_method);
+		self
+	}
 }
 
 // endregion: --- Resources
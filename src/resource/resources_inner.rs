@@ -136,6 +136,20 @@ impl ResourcesInner {
 	pub fn is_empty(&self) -> bool {
 		self.map.as_ref().is_none_or(|map| map.is_empty())
 	}
+
+	/// Returns the type names of the entries of `other` which are also in `self`
+	/// (i.e., the ones that would be overwritten by `self.extend(other)`).
+	pub fn common_type_names(&self, other: &Self) -> Vec<&'static str> {
+		let (Some(map), Some(other_map)) = (&self.map, &other.map) else {
+			return Vec::new();
+		};
+
+		other_map
+			.iter()
+			.filter(|(type_id, _)| map.contains_key(type_id))
+			.map(|(_, boxed)| (**boxed).type_name())
+			.collect()
+	}
 }
 
 // Note: Usued code for now (from `extensions.rs`, keep until refactor)
@@ -286,6 +300,7 @@ trait AnyClone: Any {
 	fn as_any(&self) -> &dyn Any;
 	fn as_any_mut(&mut self) -> &mut dyn Any;
 	fn into_any(self: Box<Self>) -> Box<dyn Any>;
+	fn type_name(&self) -> &'static str;
 }
 
 impl<T: Clone + Send + Sync + 'static> AnyClone for T {
@@ -304,6 +319,10 @@ impl<T: Clone + Send + Sync + 'static> AnyClone for T {
 	fn into_any(self: Box<Self>) -> Box<dyn Any> {
 		self
 	}
+
+	fn type_name(&self) -> &'static str {
+		std::any::type_name::<T>()
+	}
 }
 
 impl Clone for Box<dyn AnyClone + Send + Sync> {
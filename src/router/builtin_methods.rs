@@ -0,0 +1,129 @@
+use crate::router::router_inner::RouterInner;
+use crate::{Handler, HandlerError, HandlerResult};
+use std::sync::{Arc, Weak};
+
+/// The method name prefix reserved by the JSON-RPC 2.0 specification for the rpc-internal methods.
+pub const RPC_RESERVED_PREFIX: &str = "rpc.";
+
+/// Built-in method returning `"pong"` (see `RouterBuilder::rpc_ping`).
+pub const RPC_PING: &str = "rpc.ping";
+
+/// Built-in method returning the request method names (see `RouterBuilder::rpc_methods`).
+pub const RPC_METHODS: &str = "rpc.methods";
+
+/// Built-in method returning the OpenRPC document (see `RouterBuilder::rpc_discover`).
+#[cfg(feature = "schemars")]
+pub const RPC_DISCOVER: &str = "rpc.discover";
+
+/// Built-in method returning the router metrics in the Prometheus text format (see `RouterBuilder::rpc_metrics`).
+#[cfg(feature = "prometheus")]
+pub const RPC_METRICS: &str = "rpc.metrics";
+
+/// The opt-in built-in methods of a router, registered when the `Router` is built,
+/// as their handlers need the final route table.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BuiltinMethods {
+	pub ping: bool,
+	pub methods: bool,
+	#[cfg(feature = "schemars")]
+	pub discover: Option<crate::OpenRpcInfo>,
+	#[cfg(feature = "prometheus")]
+	pub metrics: bool,
+}
+
+impl BuiltinMethods {
+	/// Enables the built-in methods of `other` as well (keeping the `rpc.discover` info of `self` if any).
+	pub fn merge(&mut self, other: BuiltinMethods) {
+		self.ping |= other.ping;
+		self.methods |= other.methods;
+		#[cfg(feature = "schemars")]
+		if self.discover.is_none() {
+			self.discover = other.discover;
+		}
+		#[cfg(feature = "prometheus")]
+		{
+			self.metrics |= other.metrics;
+		}
+	}
+
+	/// Returns the names of the enabled built-in methods.
+	pub fn names(&self) -> Vec<&'static str> {
+		let mut names = Vec::new();
+		if self.ping {
+			names.push(RPC_PING);
+		}
+		if self.methods {
+			names.push(RPC_METHODS);
+		}
+		#[cfg(feature = "schemars")]
+		if self.discover.is_some() {
+			names.push(RPC_DISCOVER);
+		}
+		#[cfg(feature = "prometheus")]
+		if self.metrics {
+			names.push(RPC_METRICS);
+		}
+		names
+	}
+
+	/// Appends the enabled built-in routes to `router_inner`, which is the content of `router_inner_weak`.
+	pub fn append_routes(&self, router_inner: &mut RouterInner, router_inner_weak: &Weak<RouterInner>) {
+		if self.ping {
+			let ping = || async { HandlerResult::Ok("pong") };
+			router_inner.append_dyn(RPC_PING, ping.into_dyn());
+		}
+
+		if self.methods {
+			let router_inner_weak = router_inner_weak.clone();
+			let methods = move || {
+				let router_inner = upgrade(&router_inner_weak);
+				async move {
+					let router_inner = router_inner?;
+					let methods: Vec<String> = router_inner.methods().into_iter().map(String::from).collect();
+					HandlerResult::Ok(methods)
+				}
+			};
+			router_inner.append_dyn(RPC_METHODS, methods.into_dyn());
+		}
+
+		#[cfg(feature = "schemars")]
+		if let Some(info) = &self.discover {
+			let router_inner_weak = router_inner_weak.clone();
+			let info = info.clone();
+			let discover = move || {
+				let router_inner = upgrade(&router_inner_weak);
+				let info = info.clone();
+				async move {
+					let router_inner = router_inner?;
+					HandlerResult::Ok(crate::router::openrpc::openrpc_document(&router_inner, &info))
+				}
+			};
+			router_inner.append_dyn(RPC_DISCOVER, discover.into_dyn());
+		}
+
+		#[cfg(feature = "prometheus")]
+		if self.metrics {
+			let router_inner_weak = router_inner_weak.clone();
+			let metrics = move || {
+				let router_inner = upgrade(&router_inner_weak);
+				async move {
+					let router_inner = router_inner?;
+					let metrics = router_inner.metrics().unwrap_or_default();
+					HandlerResult::Ok(metrics.to_prometheus())
+				}
+			};
+			router_inner.append_dyn(RPC_METRICS, metrics.into_dyn());
+		}
+	}
+}
+
+// region:    --- Support
+
+/// Note: The upgrade only fails if the call outlives the router, which cannot happen for calls made through the router.
+fn upgrade(router_inner_weak: &Weak<RouterInner>) -> HandlerResult<Arc<RouterInner>> {
+	router_inner_weak
+		.upgrade()
+		.ok_or_else(|| HandlerError::new("Router dropped during the built-in method call"))
+}
+
+// endregion: --- Support
@@ -0,0 +1,45 @@
+use crate::{FromResources, RpcId};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The context of the current call, set by the router in the call `Resources` of every dispatch
+/// (requests and notifications), so handlers, guards, and layers can correlate their work (e.g., logs)
+/// without the transport building it.
+///
+/// ```
+/// pub async fn get_task(ctx: CallContext, params: ParamsIded) -> HandlerResult<Task> {
+///     println!("->> {} (id: {}, elapsed: {:?})", ctx.method, ctx.id, ctx.elapsed());
+///     ...
+/// }
+/// ```
+///
+/// Note: The context is stored inline in the `Resources` (not in the overlay), so it does not allocate per call.
+#[derive(Debug, Clone)]
+pub struct CallContext {
+	/// The json-rpc request id (`RpcId::Null` for notifications).
+	pub id: RpcId,
+	/// The json-rpc method name, as dispatched by the router (before any `Layer` rewrite).
+	pub method: Arc<str>,
+	/// True if the call is for a notification (i.e., its result will not be sent back).
+	pub notification: bool,
+	/// The time the router started the dispatch of the call.
+	pub start_time: Instant,
+}
+
+impl FromResources for CallContext {}
+
+impl CallContext {
+	pub(crate) fn new(id: RpcId, method: Arc<str>, notification: bool) -> Self {
+		Self {
+			id,
+			method,
+			notification,
+			start_time: Instant::now(),
+		}
+	}
+
+	/// Returns the time elapsed since the start of the dispatch.
+	pub fn elapsed(&self) -> Duration {
+		self.start_time.elapsed()
+	}
+}
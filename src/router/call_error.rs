@@ -1,4 +1,5 @@
 use crate::{CallSuccess, RpcId};
+use std::sync::Arc;
 
 pub type CallResult = core::result::Result<CallSuccess, CallError>;
 
@@ -12,7 +13,8 @@ pub type CallResult = core::result::Result<CallSuccess, CallError>;
 #[derive(Debug)]
 pub struct CallError {
 	pub id: RpcId,
-	pub method: String,
+	/// The method name (shared with the route table when a route matches, interned otherwise, so not allocated per call).
+	pub method: Arc<str>,
 	pub error: crate::Error,
 }
 
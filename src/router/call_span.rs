@@ -0,0 +1,32 @@
+use crate::CallResult;
+use crate::router::layer::LayerCall;
+use tracing::Span;
+use tracing::field::Empty;
+
+/// The name of the span of each call dispatch (`tracing` feature).
+pub const CALL_SPAN_NAME: &str = "rpc.call";
+
+/// Returns the span of the call dispatch, with the `rpc.id`, `rpc.method`, and `rpc.notification` fields,
+/// and the `rpc.outcome` (`"ok"` or `"error"`) and `rpc.error_kind` (see `Error::kind`) fields recorded on completion.
+pub(crate) fn call_span(call: &LayerCall) -> Span {
+	tracing::info_span!(
+		CALL_SPAN_NAME,
+		rpc.id = %call.id,
+		rpc.method = %call.method,
+		rpc.notification = call.is_notification(),
+		rpc.outcome = Empty,
+		rpc.error_kind = Empty,
+	)
+}
+
+pub(crate) fn record_outcome(span: &Span, call_result: &CallResult) {
+	match call_result {
+		Ok(_) => {
+			span.record("rpc.outcome", "ok");
+		}
+		Err(call_error) => {
+			span.record("rpc.outcome", "error");
+			span.record("rpc.error_kind", call_error.error.kind());
+		}
+	}
+}
@@ -1,5 +1,6 @@
 use crate::RpcId;
 use serde_json::Value;
+use std::sync::Arc;
 
 /// The successful response back from a `rpc_router.call...` functions.
 ///
@@ -11,6 +12,7 @@ use serde_json::Value;
 #[derive(Debug, Clone)]
 pub struct CallSuccess {
 	pub id: RpcId,
-	pub method: String,
+	/// The method name (shared with the route table when a route matches, interned otherwise, so not allocated per call).
+	pub method: Arc<str>,
 	pub value: Value,
 }
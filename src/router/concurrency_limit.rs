@@ -0,0 +1,79 @@
+use crate::Error;
+use std::sync::Arc;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// What to do with a call when the concurrency limit of its method is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConcurrencyLimitMode {
+	/// The call waits for a running call to complete (within the eventual timeout).
+	Queue,
+	/// The call fails immediately with `Error::ConcurrencyLimitReached`.
+	Reject,
+}
+
+/// The maximum number of concurrent executions of a method (see `RouterBuilder::method_concurrency_limit`).
+///
+/// The limit is tracked by the router, so every transport calling the router gets the same protection.
+///
+/// Note: Clones of a `ConcurrencyLimit` share the same count, so the same `ConcurrencyLimit`
+///       given to multiple methods limits their combined concurrency.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimit {
+	max: usize,
+	mode: ConcurrencyLimitMode,
+	semaphore: Arc<Semaphore>,
+}
+
+// -- Constructors
+impl ConcurrencyLimit {
+	/// Note: A `max` of 0 is treated as 1 (otherwise, the calls would never run),
+	///       and a `max` over `Semaphore::MAX_PERMITS` (e.g., `usize::MAX`) is treated as `Semaphore::MAX_PERMITS`.
+	pub fn new(max: usize, mode: ConcurrencyLimitMode) -> Self {
+		let max = max.clamp(1, Semaphore::MAX_PERMITS);
+		Self {
+			max,
+			mode,
+			semaphore: Arc::new(Semaphore::new(max)),
+		}
+	}
+
+	/// Limit where the calls over `max` wait for a running call to complete.
+	pub fn queue(max: usize) -> Self {
+		Self::new(max, ConcurrencyLimitMode::Queue)
+	}
+
+	/// Limit where the calls over `max` fail immediately with `Error::ConcurrencyLimitReached`.
+	pub fn reject(max: usize) -> Self {
+		Self::new(max, ConcurrencyLimitMode::Reject)
+	}
+}
+
+// -- Accessors
+impl ConcurrencyLimit {
+	pub fn max(&self) -> usize {
+		self.max
+	}
+
+	pub fn mode(&self) -> ConcurrencyLimitMode {
+		self.mode
+	}
+
+	/// Returns the number of calls which can start without waiting (or being rejected).
+	pub fn available(&self) -> usize {
+		self.semaphore.available_permits()
+	}
+}
+
+// -- Crate
+impl ConcurrencyLimit {
+	/// Returns the permit to hold for the duration of the call.
+	pub(crate) async fn acquire(&self) -> Result<SemaphorePermit<'_>, Error> {
+		let permit = match self.mode {
+			ConcurrencyLimitMode::Queue => self.semaphore.acquire().await.ok(),
+			ConcurrencyLimitMode::Reject => self.semaphore.try_acquire().ok(),
+		};
+
+		// Note: The semaphore is never closed, so `None` is always the limit being reached.
+		permit.ok_or(Error::ConcurrencyLimitReached(self.max))
+	}
+}
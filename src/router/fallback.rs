@@ -0,0 +1,23 @@
+use crate::FromResources;
+use std::sync::Arc;
+
+/// The resource available to the fallback handler (see `RouterBuilder::fallback`),
+/// with the method name of the unmatched call.
+///
+/// ```
+/// pub async fn fallback(method: FallbackMethod, params: Option<Value>) -> HandlerResult<Value> {
+///     // e.g., forward `method.as_str()` and `params` to a legacy backend
+/// }
+/// ```
+///
+/// Note: The method name is shared with the call (not copied), and the resource is stored inline in the `Resources`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackMethod(pub Arc<str>);
+
+impl FromResources for FallbackMethod {}
+
+impl FallbackMethod {
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
@@ -0,0 +1,78 @@
+use crate::{Resources, RouteMeta};
+use serde::Serialize;
+
+/// A `Guard` authorizes a call before its params are parsed and its handler is executed,
+/// and rejects it with a `GuardRejection` (mapped to an "Unauthorized" or "Forbidden" `RpcError`).
+///
+/// Guards are registered for all the routes of a builder with `RouterBuilder::guard`
+/// (e.g., for a nested prefix with the guard on the nested builder), or per method with `RouterBuilder::method_guard`.
+///
+/// Guards are evaluated after the layers, in registration order (router guards first, then route guards),
+/// and the first rejection stops the call.
+///
+/// ```
+/// // A closure `Fn(&GuardCall) -> Result<(), GuardRejection>` is a Guard.
+/// let require_ctx = |call: &GuardCall| match call.resources.get::<Ctx>() {
+///     Some(_) => Ok(()),
+///     None => Err(GuardRejection::unauthorized("No Ctx")),
+/// };
+///
+/// let router = Router::builder().guard(require_ctx).append("list_tasks", list_tasks).build();
+/// ```
+///
+/// Note: Guards are synchronous, for async checks (e.g., database lookup), use a `Layer`.
+pub trait Guard: Send + Sync + 'static {
+	fn check(&self, call: &GuardCall) -> Result<(), GuardRejection>;
+}
+
+impl<F> Guard for F
+where
+	F: Fn(&GuardCall) -> Result<(), GuardRejection> + Send + Sync + 'static,
+{
+	fn check(&self, call: &GuardCall) -> Result<(), GuardRejection> {
+		self(call)
+	}
+}
+
+/// The call information given to the `Guard`.
+#[derive(Debug)]
+pub struct GuardCall<'a> {
+	/// The json-rpc method name.
+	pub method: &'a str,
+	/// The metadata of the matched route (see `RouterBuilder::append_with_meta`).
+	pub meta: &'a RouteMeta,
+	/// The resources of this call (base router resources with eventual call overlay, and the `CallContext`).
+	pub resources: &'a Resources,
+}
+
+/// The rejection of a call by a `Guard`, with its reason.
+///
+/// - `Unauthorized` - The caller is not authenticated (mapped to `RpcError::CODE_UNAUTHORIZED`).
+/// - `Forbidden` - The caller is not allowed to call this method (mapped to `RpcError::CODE_FORBIDDEN`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum GuardRejection {
+	Unauthorized(String),
+	Forbidden(String),
+}
+
+impl GuardRejection {
+	pub fn unauthorized(reason: impl Into<String>) -> Self {
+		Self::Unauthorized(reason.into())
+	}
+
+	pub fn forbidden(reason: impl Into<String>) -> Self {
+		Self::Forbidden(reason.into())
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for GuardRejection {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for GuardRejection {}
+
+// endregion: --- Error Boilerplate
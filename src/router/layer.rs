@@ -0,0 +1,229 @@
+use crate::router::route::Route;
+use crate::router::router_inner::RouterInner;
+use crate::{CallContext, CallError, CallResult, Error, FallbackMethod, Resources, RouteMeta, RpcId};
+use futures::future::{BoxFuture, ready};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// The future returned by `Layer::call` and `Next::run`.
+pub type LayerFuture<'a> = BoxFuture<'a, CallResult>;
+
+/// A `Layer` wraps every call dispatched by the router (requests and notifications),
+/// to implement cross-cutting concerns (auth checks, logging, timing, error rewriting, ...).
+///
+/// - The layer receives the `LayerCall` before the handler is called (and before the params are parsed),
+///   and can modify it, or short-circuit the call by returning a `CallResult` without calling `next.run(call)`.
+/// - The layer gets the `CallResult` back from `next.run(call).await`, and can inspect or rewrite it.
+///
+/// Ordering: Layers are executed in the order they were added with `RouterBuilder::layer(..)`.
+///           The first added layer is the outermost one (first to see the call, last to see the result).
+///
+/// ```
+/// struct LogLayer;
+///
+/// impl Layer for LogLayer {
+///     fn call<'a>(&'a self, call: LayerCall, next: Next<'a>) -> LayerFuture<'a> {
+///         Box::pin(async move {
+///             println!("->> {}", call.method);
+///             let res = next.run(call).await;
+///             println!("<<- ok: {}", res.is_ok());
+///             res
+///         })
+///     }
+/// }
+///
+/// let router = Router::builder().layer(LogLayer).append("my_method", my_method).build();
+/// ```
+pub trait Layer: Send + Sync + 'static {
+	fn call<'a>(&'a self, call: LayerCall, next: Next<'a>) -> LayerFuture<'a>;
+}
+
+/// The call information given to the `Layer`, before the handler execution.
+///
+/// All the fields can be modified by the layer before calling `next.run(call)`.
+#[derive(Debug)]
+pub struct LayerCall {
+	/// The json-rpc request id (`RpcId::Null` for notifications).
+	pub id: RpcId,
+	/// The json-rpc method name (shared with the route table when a route matches).
+	pub method: Arc<str>,
+	/// The json-rpc params (not parsed yet).
+	pub params: Option<Value>,
+	/// The resources of this call (base router resources with eventual call overlay, and the `CallContext`).
+	pub resources: Resources,
+	pub(crate) notification: bool,
+}
+
+impl LayerCall {
+	pub(crate) fn new(id: RpcId, method: Arc<str>, params: Option<Value>, resources: Resources) -> Self {
+		let call_context = CallContext::new(id.clone(), method.clone(), false);
+		Self {
+			id,
+			method,
+			params,
+			resources: resources.with_call_context(call_context),
+			notification: false,
+		}
+	}
+
+	pub(crate) fn new_notification(method: Arc<str>, params: Option<Value>, resources: Resources) -> Self {
+		let call_context = CallContext::new(RpcId::Null, method.clone(), true);
+		Self {
+			id: RpcId::Null,
+			method,
+			params,
+			resources: resources.with_call_context(call_context),
+			notification: true,
+		}
+	}
+
+	/// Returns true if this call is for a notification (i.e., its result will not be sent back).
+	pub fn is_notification(&self) -> bool {
+		self.notification
+	}
+}
+
+/// The remaining of the layer pipeline, ending with the route handler.
+///
+/// The pipeline is: router layers -> route lookup -> route layers (e.g., from nested builders) -> guards -> route handler.
+///
+/// When the route lookup has no match, the fallback handler (if any) is called with the `FallbackMethod` resource.
+pub struct Next<'a> {
+	router_inner: &'a RouterInner,
+	layers: &'a [Arc<dyn Layer>],
+	/// The route resolved by the router for the dispatched method (reused unless a layer rewrites the method).
+	dispatched: &'a DispatchedRoute<'a>,
+	/// The route once resolved (after the router layers).
+	route: Option<&'a Route>,
+}
+
+/// The route lookup done by the router before the layers, for the method it dispatched.
+pub(crate) struct DispatchedRoute<'a> {
+	pub method: Arc<str>,
+	pub route: Option<&'a Route>,
+}
+
+impl<'a> Next<'a> {
+	pub(crate) fn new(
+		router_inner: &'a RouterInner,
+		layers: &'a [Arc<dyn Layer>],
+		dispatched: &'a DispatchedRoute<'a>,
+	) -> Self {
+		Self {
+			router_inner,
+			layers,
+			dispatched,
+			route: None,
+		}
+	}
+
+	/// Returns the metadata of the route matching this call (see `RouterBuilder::append_with_meta`),
+	/// or None if no route matches (e.g., unknown method or fallback).
+	pub fn route_meta(&self, call: &LayerCall) -> Option<&'a RouteMeta> {
+		self.matched_route(call).map(|route| route.meta().as_ref())
+	}
+
+	/// Returns the name of the route matching this call (shared with the route table),
+	/// or None if no route matches (e.g., unknown method or fallback).
+	pub(crate) fn route_name(&self, call: &LayerCall) -> Option<&'a Arc<str>> {
+		self.matched_route(call).map(|route| route.name())
+	}
+
+	/// Returns the route matching this call, without a new lookup unless a layer rewrote the method.
+	fn matched_route(&self, call: &LayerCall) -> Option<&'a Route> {
+		match self.route {
+			// Note: In the fallback layers, the resolved route is the fallback, so no route matches.
+			Some(route) => {
+				let is_fallback = self
+					.router_inner
+					.fallback()
+					.is_some_and(|fallback| std::ptr::eq(fallback, route));
+				(!is_fallback).then_some(route)
+			}
+			None => lookup_route(self.router_inner, self.dispatched, call),
+		}
+	}
+
+	/// Runs the next layer, or the route handler if this was the last layer.
+	pub fn run(self, mut call: LayerCall) -> LayerFuture<'a> {
+		let Next {
+			router_inner,
+			layers,
+			dispatched,
+			route,
+		} = self;
+
+		if let Some((layer, layers)) = layers.split_first() {
+			return layer.call(
+				call,
+				Next {
+					router_inner,
+					layers,
+					dispatched,
+					route,
+				},
+			);
+		}
+
+		match route {
+			Some(route) => {
+				if let Err(rejection) = router_inner.check_guards(route, &call) {
+					return Box::pin(ready(Err(CallError {
+						id: call.id,
+						method: call.method,
+						error: Error::GuardRejected(rejection),
+					})));
+				}
+
+				#[cfg(feature = "tokio")]
+				if let Some(timeout) = route.timeout().or(router_inner.default_timeout()) {
+					return Box::pin(route.call_handler_with_timeout(call, timeout));
+				}
+				Box::pin(route.call_handler(call))
+			}
+			None => match lookup_route(router_inner, dispatched, &call) {
+				Some(route) => Next {
+					router_inner,
+					layers: route.layers(),
+					dispatched,
+					route: Some(route),
+				}
+				.run(call),
+				None => match router_inner.fallback() {
+					Some(fallback) => {
+						call.resources = call.resources.with_fallback_method(FallbackMethod(call.method.clone()));
+						Next {
+							router_inner,
+							layers: fallback.layers(),
+							dispatched,
+							route: Some(fallback),
+						}
+						.run(call)
+					}
+					None => Box::pin(ready(Err(CallError {
+						id: call.id,
+						method: call.method,
+						error: Error::MethodUnknown,
+					}))),
+				},
+			},
+		}
+	}
+}
+
+// region:    --- Support
+
+/// Returns the route of the call method, without a new lookup if the method was not rewritten by a layer.
+fn lookup_route<'a>(
+	router_inner: &'a RouterInner,
+	dispatched: &DispatchedRoute<'a>,
+	call: &LayerCall,
+) -> Option<&'a Route> {
+	if Arc::ptr_eq(&dispatched.method, &call.method) {
+		dispatched.route
+	} else {
+		router_inner.get_route(call)
+	}
+}
+
+// endregion: --- Support
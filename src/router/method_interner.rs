@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+/// The maximum number of interned method names before the interner is cleared,
+/// so callers sending arbitrary method names cannot grow it without bound.
+const MAX_INTERNED_METHODS: usize = 1_024;
+
+/// The interned names of the methods without a route (e.g., dispatched to the fallback, or `Error::MethodUnknown`),
+/// so the repeated calls of the same unknown method share its `Arc<str>` instead of allocating it per call.
+///
+/// Note: The names of the methods with a route are shared from the route table (see `Route::name`).
+#[derive(Default)]
+pub(crate) struct MethodInterner {
+	names: RwLock<HashSet<Arc<str>>>,
+}
+
+impl MethodInterner {
+	/// Returns the shared name of this method, interning it on first call.
+	pub fn intern(&self, method: &str) -> Arc<str> {
+		// Note: The lock only guards the name set, so a poisoned lock still holds valid names.
+		if let Some(name) = self.names.read().unwrap_or_else(|err| err.into_inner()).get(method) {
+			return name.clone();
+		}
+
+		let mut names = self.names.write().unwrap_or_else(|err| err.into_inner());
+		if let Some(name) = names.get(method) {
+			return name.clone();
+		}
+		if names.len() >= MAX_INTERNED_METHODS {
+			names.clear();
+		}
+		let name: Arc<str> = Arc::from(method);
+		names.insert(name.clone());
+		name
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_method_interner_intern() {
+		// -- Setup & Fixtures
+		let interner = MethodInterner::default();
+
+		// -- Exec
+		let first = interner.intern("legacy_get_task");
+		let second = interner.intern("legacy_get_task");
+		for idx in 0..MAX_INTERNED_METHODS {
+			interner.intern(&format!("unknown_{idx}"));
+		}
+
+		// -- Check
+		assert!(Arc::ptr_eq(&first, &second));
+		assert!(interner.names.read().unwrap().len() <= MAX_INTERNED_METHODS);
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,242 @@
+use crate::CallResult;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+/// The method name under which the calls without a matching route (unknown method, or fallback) are recorded,
+/// so the number of recorded methods stays bounded by the route table.
+pub const METRICS_UNMATCHED_METHOD: &str = "<unmatched>";
+
+/// The upper bounds of the latency histogram buckets (the last bucket, `+Inf`, is the `count`).
+pub const METRICS_LATENCY_BUCKETS: [Duration; 14] = [
+	Duration::from_micros(500),
+	Duration::from_millis(1),
+	Duration::from_micros(2_500),
+	Duration::from_millis(5),
+	Duration::from_millis(10),
+	Duration::from_millis(25),
+	Duration::from_millis(50),
+	Duration::from_millis(100),
+	Duration::from_millis(250),
+	Duration::from_millis(500),
+	Duration::from_secs(1),
+	Duration::from_millis(2_500),
+	Duration::from_secs(5),
+	Duration::from_secs(10),
+];
+
+// region:    --- Snapshot
+
+/// The snapshot of the router metrics (see `RouterBuilder::enable_metrics` and `Router::metrics`).
+#[derive(Debug, Clone, Default)]
+pub struct RouterMetrics {
+	/// The metrics of each called method, by method name.
+	pub methods: Vec<MethodMetrics>,
+}
+
+impl RouterMetrics {
+	/// Returns the metrics of this method, if it was called.
+	pub fn method(&self, name: &str) -> Option<&MethodMetrics> {
+		self.methods.iter().find(|method_metrics| &*method_metrics.method == name)
+	}
+}
+
+/// The metrics of a method (requests and notifications).
+///
+/// Note: The calls dropped before completion (e.g., cancelled by the transport) are not counted in `calls`,
+///       but leave the `in_flight` gauge.
+#[derive(Debug, Clone)]
+pub struct MethodMetrics {
+	pub method: Arc<str>,
+	/// The number of completed calls (success or error).
+	pub calls: u64,
+	/// The number of completed calls with an error.
+	pub errors: u64,
+	/// The number of errors by `Error::kind` (e.g., `"MethodUnknown"`, `"ParamsParsing"`).
+	pub errors_by_kind: BTreeMap<&'static str, u64>,
+	/// The number of calls currently running.
+	pub in_flight: u64,
+	pub latency: LatencyHistogram,
+}
+
+/// The latency histogram of the completed calls of a method (from the dispatch to the `CallResult`,
+/// including the layers, guards, params parsing, and handler).
+#[derive(Debug, Clone)]
+pub struct LatencyHistogram {
+	/// The cumulative count of calls for each bucket upper bound (see `METRICS_LATENCY_BUCKETS`).
+	pub buckets: Vec<(Duration, u64)>,
+	pub count: u64,
+	pub sum: Duration,
+}
+
+// endregion: --- Snapshot
+
+// region:    --- MetricsRegistry
+
+/// The per-method metrics recorders of a router.
+///
+/// Note: The registry is shared by the clones of the route table (see `Router::to_builder`),
+///       so the metrics survive the updates of a `ReloadableRouter`.
+#[derive(Default)]
+pub(crate) struct MetricsRegistry {
+	recorders: RwLock<HashMap<Arc<str>, Arc<MethodRecorder>>>,
+	/// The recorder of `METRICS_UNMATCHED_METHOD` (also in `recorders`), kept to avoid a lookup per unmatched call.
+	unmatched_recorder: OnceLock<Arc<MethodRecorder>>,
+}
+
+impl MetricsRegistry {
+	/// Returns the recorder of this method, creating it on first call.
+	///
+	/// Note: Called at router build for the routes (see `RouterInner::resolve_route_recorders`), not per call.
+	pub fn method_recorder(&self, method: &Arc<str>) -> Arc<MethodRecorder> {
+		// Note: The lock only guards the recorder map, so a poisoned lock still holds valid recorders.
+		if let Some(recorder) = self.recorders.read().unwrap_or_else(|err| err.into_inner()).get(method) {
+			return recorder.clone();
+		}
+
+		self.recorders
+			.write()
+			.unwrap_or_else(|err| err.into_inner())
+			.entry(method.clone())
+			.or_default()
+			.clone()
+	}
+
+	/// Returns the recorder of the calls without a matching route (see `METRICS_UNMATCHED_METHOD`).
+	pub fn unmatched_recorder(&self) -> &Arc<MethodRecorder> {
+		self.unmatched_recorder
+			.get_or_init(|| self.method_recorder(&Arc::from(METRICS_UNMATCHED_METHOD)))
+	}
+
+	/// Returns the snapshot of the called methods (the recorders of the routes never called are skipped).
+	pub fn snapshot(&self) -> RouterMetrics {
+		let recorders = self.recorders.read().unwrap_or_else(|err| err.into_inner());
+		let mut methods: Vec<MethodMetrics> = recorders
+			.iter()
+			.map(|(method, recorder)| recorder.snapshot(method.clone()))
+			.filter(|method_metrics| method_metrics.calls > 0 || method_metrics.in_flight > 0)
+			.collect();
+		methods.sort_by(|a, b| a.method.cmp(&b.method));
+
+		RouterMetrics { methods }
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct MethodRecorder {
+	calls: AtomicU64,
+	in_flight: AtomicU64,
+	errors_by_kind: Mutex<BTreeMap<&'static str, u64>>,
+	latency_buckets: [AtomicU64; METRICS_LATENCY_BUCKETS.len()],
+	latency_sum_micros: AtomicU64,
+}
+
+impl MethodRecorder {
+	/// Runs the call future, and records its outcome and latency.
+	pub async fn record(&self, call_fut: impl Future<Output = CallResult>) -> CallResult {
+		let start = Instant::now();
+		self.in_flight.fetch_add(1, Ordering::Relaxed);
+		let _in_flight = InFlightGuard(&self.in_flight);
+
+		let call_result = call_fut.await;
+
+		let elapsed = start.elapsed();
+		self.calls.fetch_add(1, Ordering::Relaxed);
+		self.latency_sum_micros.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+		if let Some(idx) = METRICS_LATENCY_BUCKETS.iter().position(|bound| elapsed <= *bound) {
+			self.latency_buckets[idx].fetch_add(1, Ordering::Relaxed);
+		}
+		if let Err(call_error) = &call_result {
+			let mut errors_by_kind = self.errors_by_kind.lock().unwrap_or_else(|err| err.into_inner());
+			*errors_by_kind.entry(call_error.error.kind()).or_default() += 1;
+		}
+
+		call_result
+	}
+
+	fn snapshot(&self, method: Arc<str>) -> MethodMetrics {
+		let errors_by_kind = self.errors_by_kind.lock().unwrap_or_else(|err| err.into_inner()).clone();
+
+		let mut cumulative = 0;
+		let buckets = METRICS_LATENCY_BUCKETS
+			.iter()
+			.zip(&self.latency_buckets)
+			.map(|(bound, count)| {
+				cumulative += count.load(Ordering::Relaxed);
+				(*bound, cumulative)
+			})
+			.collect();
+
+		let calls = self.calls.load(Ordering::Relaxed);
+		MethodMetrics {
+			method,
+			calls,
+			errors: errors_by_kind.values().sum(),
+			errors_by_kind,
+			in_flight: self.in_flight.load(Ordering::Relaxed),
+			latency: LatencyHistogram {
+				buckets,
+				count: calls,
+				sum: Duration::from_micros(self.latency_sum_micros.load(Ordering::Relaxed)),
+			},
+		}
+	}
+}
+
+/// Decrements the in-flight gauge when the call completes or is dropped.
+struct InFlightGuard<'a>(&'a AtomicU64);
+
+impl Drop for InFlightGuard<'_> {
+	fn drop(&mut self) {
+		self.0.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+// endregion: --- MetricsRegistry
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{CallError, CallSuccess, Error, RpcId};
+	use serde_json::Value;
+
+	#[tokio::test]
+	async fn test_method_recorder_record() {
+		// -- Setup & Fixtures
+		let recorder = MethodRecorder::default();
+		let method: Arc<str> = Arc::from("get_task");
+		let success = || async {
+			Ok(CallSuccess {
+				id: RpcId::Null,
+				method: Arc::from("get_task"),
+				value: Value::Null,
+			})
+		};
+		let failure = || async {
+			Err(CallError {
+				id: RpcId::Null,
+				method: Arc::from("get_task"),
+				error: Error::ParamsMissingButRequested,
+			})
+		};
+
+		// -- Exec
+		let _ = recorder.record(success()).await;
+		let _ = recorder.record(failure()).await;
+		let metrics = recorder.snapshot(method);
+
+		// -- Check
+		assert_eq!(metrics.calls, 2);
+		assert_eq!(metrics.errors, 1);
+		assert_eq!(metrics.errors_by_kind.get("ParamsMissingButRequested"), Some(&1));
+		assert_eq!(metrics.in_flight, 0);
+		assert_eq!(metrics.latency.count, 2);
+		assert_eq!(metrics.latency.buckets.last().map(|(_, count)| *count), Some(2));
+	}
+}
+
+// endregion: --- Tests
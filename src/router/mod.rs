@@ -2,17 +2,72 @@
 
 // region:    --- Modules
 
+mod builtin_methods;
+mod call_context;
 mod call_error;
+#[cfg(feature = "tracing")]
+mod call_span;
 mod call_success;
+#[cfg(feature = "tokio")]
+mod concurrency_limit;
+mod fallback;
+mod guard;
+mod layer;
+mod method_interner;
+mod metrics;
+mod notification_error_policy;
+#[cfg(feature = "schemars")]
+mod openrpc;
+#[cfg(feature = "prometheus")]
+mod prometheus;
+mod rate_limit;
+mod reloadable_router;
+mod route;
+mod route_info;
+mod route_meta;
+#[cfg(feature = "schemars")]
+mod route_schema;
 mod router;
+mod router_build_error;
 mod router_builder;
 mod router_builder_macro;
 mod router_inner;
+#[cfg(feature = "tower")]
+mod router_tower;
+mod static_lookup;
+mod static_router_builder_macro;
 
 // -- Flatten
+#[cfg(feature = "schemars")]
+pub use builtin_methods::RPC_DISCOVER;
+#[cfg(feature = "prometheus")]
+pub use builtin_methods::RPC_METRICS;
+pub use builtin_methods::{RPC_METHODS, RPC_PING, RPC_RESERVED_PREFIX};
+pub use call_context::*;
 pub use call_error::*;
+#[cfg(feature = "tracing")]
+pub use call_span::CALL_SPAN_NAME;
 pub use call_success::*;
+#[cfg(feature = "tokio")]
+pub use concurrency_limit::*;
+pub use fallback::*;
+pub use guard::*;
+pub use layer::*;
+pub use metrics::{LatencyHistogram, METRICS_LATENCY_BUCKETS, METRICS_UNMATCHED_METHOD, MethodMetrics, RouterMetrics};
+pub use notification_error_policy::*;
+#[cfg(feature = "schemars")]
+pub use openrpc::{OPENRPC_VERSION, OpenRpcInfo};
+#[cfg(feature = "prometheus")]
+pub use prometheus::PROMETHEUS_METRIC_PREFIX;
+pub use rate_limit::*;
+pub use reloadable_router::*;
+pub use route_info::*;
+pub use route_meta::*;
+#[cfg(feature = "schemars")]
+pub use route_schema::*;
 pub use router::*;
+pub use router_build_error::*;
 pub use router_builder::*;
+pub use static_lookup::*;
 
 // endregion: --- Modules
@@ -0,0 +1,43 @@
+use crate::CallError;
+use std::fmt;
+use std::sync::Arc;
+
+/// The hook function type called when a notification handler fails.
+pub type NotificationErrorHook = Arc<dyn Fn(&CallError) + Send + Sync>;
+
+/// Defines what the router does when a notification fails (e.g., unknown method, params parsing, handler error).
+///
+/// Since notifications do not get a response, their errors cannot be returned to the caller.
+///
+/// - `Ignore` (default) - The error is silently dropped.
+/// - `Hook` - The error is passed to the hook function (e.g., for logging).
+///   The `CallError.id` is always `RpcId::Null` as notifications have no id.
+#[derive(Clone, Default)]
+pub enum NotificationErrorPolicy {
+	#[default]
+	Ignore,
+	Hook(NotificationErrorHook),
+}
+
+impl NotificationErrorPolicy {
+	/// Convenient constructor for the `Hook` variant.
+	pub fn hook(hook_fn: impl Fn(&CallError) + Send + Sync + 'static) -> Self {
+		Self::Hook(Arc::new(hook_fn))
+	}
+
+	pub(crate) fn handle(&self, call_error: &CallError) {
+		match self {
+			NotificationErrorPolicy::Ignore => (),
+			NotificationErrorPolicy::Hook(hook_fn) => hook_fn(call_error),
+		}
+	}
+}
+
+impl fmt::Debug for NotificationErrorPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			NotificationErrorPolicy::Ignore => f.write_str("Ignore"),
+			NotificationErrorPolicy::Hook(_) => f.write_str("Hook(..)"),
+		}
+	}
+}
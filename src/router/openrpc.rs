@@ -0,0 +1,122 @@
+use crate::router::router_inner::RouterInner;
+use crate::{RouteInfo, RouteKind, RouteSchema};
+use schemars::SchemaGenerator;
+use schemars::generate::SchemaSettings;
+use serde_json::{Map, Value, json};
+
+/// The OpenRPC specification version of the generated documents.
+pub const OPENRPC_VERSION: &str = "1.3.2";
+
+/// The `info` of the generated OpenRPC document (see `Router::openrpc`).
+#[derive(Debug, Clone)]
+pub struct OpenRpcInfo {
+	pub title: String,
+	pub version: String,
+	pub description: Option<String>,
+}
+
+impl OpenRpcInfo {
+	pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+		Self {
+			title: title.into(),
+			version: version.into(),
+			description: None,
+		}
+	}
+
+	pub fn with_description(mut self, description: impl Into<String>) -> Self {
+		self.description = Some(description.into());
+		self
+	}
+
+	fn to_value(&self) -> Value {
+		let mut info = json!({
+			"title": self.title,
+			"version": self.version,
+		});
+		if let Some(description) = &self.description {
+			info["description"] = description.clone().into();
+		}
+		info
+	}
+}
+
+/// Builds the OpenRPC document of the request routes, in registration order.
+///
+/// - Routes with a `RouteSchema` metadata get their params (by-name for object params) and result schemas.
+/// - Routes without a `RouteSchema` are listed without params, and with an unconstrained result schema.
+/// - The named types are in `components.schemas`.
+pub(crate) fn openrpc_document(router_inner: &RouterInner, info: &OpenRpcInfo) -> Value {
+	// Note: OpenRPC schemas are JSON Schema (draft 7), not OpenAPI 3.0 schemas (e.g., `nullable`),
+	//       with the named types in `components.schemas`.
+	let mut generator = SchemaGenerator::new(SchemaSettings::draft07().with(|settings| {
+		settings.definitions_path = "/components/schemas".into();
+		settings.meta_schema = None;
+	}));
+
+	let methods: Vec<Value> = router_inner
+		.routes()
+		.iter()
+		.filter(|route| route.kind == RouteKind::Request)
+		.map(|route| method_value(route, &mut generator))
+		.collect();
+
+	json!({
+		"openrpc": OPENRPC_VERSION,
+		"info": info.to_value(),
+		"methods": methods,
+		"components": {
+			"schemas": generator.take_definitions(true),
+		},
+	})
+}
+
+// region:    --- Support
+
+fn method_value(route: &RouteInfo, generator: &mut SchemaGenerator) -> Value {
+	let mut method = Map::new();
+	method.insert("name".into(), route.name.as_ref().into());
+
+	let Some(route_schema) = route.meta.get::<RouteSchema>() else {
+		method.insert("params".into(), json!([]));
+		method.insert("result".into(), json!({"name": "result", "schema": {}}));
+		return Value::Object(method);
+	};
+
+	let (params, param_structure) = match route_schema.params_schema(generator) {
+		None => (Vec::new(), None),
+		Some(params_schema) => match params_schema.get("properties").and_then(Value::as_object) {
+			// Object params are described property by property (e.g., `{"id": 123}`).
+			Some(properties) => {
+				let required = params_schema.get("required").and_then(Value::as_array);
+				let is_required = |name: &str| required.is_some_and(|required| required.iter().any(|r| r == name));
+				let params = properties
+					.iter()
+					.map(|(name, schema)| {
+						json!({
+							"name": name,
+							"schema": schema,
+							"required": is_required(name),
+						})
+					})
+					.collect();
+				(params, Some("by-name"))
+			}
+			// Other params are described as a single `params` value.
+			None => (vec![json!({"name": "params", "schema": params_schema})], None),
+		},
+	};
+
+	method.insert("params".into(), Value::Array(params));
+	if let Some(param_structure) = param_structure {
+		method.insert("paramStructure".into(), param_structure.into());
+	}
+	method.insert(
+		"result".into(),
+		json!({"name": "result", "schema": route_schema.result_schema(generator)}),
+	);
+
+	Value::Object(method)
+}
+
+// endregion: --- Support
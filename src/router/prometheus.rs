@@ -0,0 +1,129 @@
+use crate::RouterMetrics;
+use std::fmt::Write;
+
+/// The prefix of the metric names of the Prometheus text exposition.
+pub const PROMETHEUS_METRIC_PREFIX: &str = "rpc_router";
+
+impl RouterMetrics {
+	/// Renders the metrics in the Prometheus text exposition format (version 0.0.4),
+	/// with a `method` label (and a `kind` label for the errors):
+	///
+	/// - `rpc_router_calls_total` (counter) - The completed calls.
+	/// - `rpc_router_errors_total` (counter) - The completed calls with an error, by `Error::kind`.
+	/// - `rpc_router_in_flight` (gauge) - The calls currently running.
+	/// - `rpc_router_call_duration_seconds` (histogram) - The latency of the completed calls.
+	pub fn to_prometheus(&self) -> String {
+		let mut out = String::new();
+		let prefix = PROMETHEUS_METRIC_PREFIX;
+
+		// Note: Writing to a `String` cannot fail.
+		header(
+			&mut out,
+			&format!("{prefix}_calls_total"),
+			"counter",
+			"The number of completed calls.",
+		);
+		for method_metrics in &self.methods {
+			let method = escape_label_value(&method_metrics.method);
+			let _ = writeln!(
+				out,
+				"{prefix}_calls_total{{method=\"{method}\"}} {}",
+				method_metrics.calls
+			);
+		}
+
+		header(
+			&mut out,
+			&format!("{prefix}_errors_total"),
+			"counter",
+			"The number of completed calls with an error, by error kind.",
+		);
+		for method_metrics in &self.methods {
+			let method = escape_label_value(&method_metrics.method);
+			for (kind, count) in &method_metrics.errors_by_kind {
+				let _ = writeln!(
+					out,
+					"{prefix}_errors_total{{method=\"{method}\",kind=\"{kind}\"}} {count}"
+				);
+			}
+		}
+
+		header(
+			&mut out,
+			&format!("{prefix}_in_flight"),
+			"gauge",
+			"The number of calls currently running.",
+		);
+		for method_metrics in &self.methods {
+			let method = escape_label_value(&method_metrics.method);
+			let _ = writeln!(
+				out,
+				"{prefix}_in_flight{{method=\"{method}\"}} {}",
+				method_metrics.in_flight
+			);
+		}
+
+		let name = format!("{prefix}_call_duration_seconds");
+		header(
+			&mut out,
+			&name,
+			"histogram",
+			"The latency of the completed calls, in seconds.",
+		);
+		for method_metrics in &self.methods {
+			let method = escape_label_value(&method_metrics.method);
+			let latency = &method_metrics.latency;
+			for (bound, count) in &latency.buckets {
+				let le = bound.as_secs_f64();
+				let _ = writeln!(out, "{name}_bucket{{method=\"{method}\",le=\"{le}\"}} {count}");
+			}
+			let _ = writeln!(
+				out,
+				"{name}_bucket{{method=\"{method}\",le=\"+Inf\"}} {}",
+				latency.count
+			);
+			let _ = writeln!(out, "{name}_sum{{method=\"{method}\"}} {}", latency.sum.as_secs_f64());
+			let _ = writeln!(out, "{name}_count{{method=\"{method}\"}} {}", latency.count);
+		}
+
+		out
+	}
+}
+
+// region:    --- Support
+
+fn header(out: &mut String, name: &str, metric_type: &str, help: &str) {
+	let _ = writeln!(out, "# HELP {name} {help}");
+	let _ = writeln!(out, "# TYPE {name} {metric_type}");
+}
+
+/// Escapes the backslash, double-quote, and line feed of a label value (per the exposition format).
+fn escape_label_value(value: &str) -> String {
+	let mut escaped = String::with_capacity(value.len());
+	for c in value.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			'"' => escaped.push_str("\\\""),
+			'\n' => escaped.push_str("\\n"),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_escape_label_value() {
+		assert_eq!(escape_label_value("get_task"), "get_task");
+		assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+	}
+}
+
+// endregion: --- Tests
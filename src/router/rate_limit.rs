@@ -0,0 +1,210 @@
+use crate::router::layer::{Layer, LayerCall, LayerFuture, Next};
+use crate::{CallError, Error, Resources};
+use futures::future::ready;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The maximum number of buckets per method, beyond which the least recently used half is evicted.
+const MAX_BUCKETS: usize = 10_000;
+
+/// A token bucket rate limiter `Layer`, with one bucket per method and per key
+/// (e.g., a `UserId` resource overlaid with `call_with_resources`).
+///
+/// - Each bucket holds up to `capacity` tokens, and is refilled at the rate of `capacity` tokens per `period`.
+/// - Each call takes one token, or fails with `Error::RateLimited` (with the time to wait for the next token).
+///
+/// The `RateLimit` can be added to all the routes with `RouterBuilder::layer`,
+/// or to some routes with `RouterBuilder::method_layer` (or the layer of a nested builder).
+///
+/// ```
+/// let router = Router::builder()
+///     .append("create_task", create_task)
+///     // 10 calls per second, per method and per UserId
+///     .method_layer("create_task", RateLimit::new(10, Duration::from_secs(1)).keyed_by::<UserId>())
+///     .build();
+/// ```
+///
+/// Note: The calls without the key resource share the same bucket (per method),
+///       and the calls without a matching route (e.g., unknown method names) share the same buckets (per key).
+///       Each method holds up to 10,000 buckets (i.e., keys), beyond which the least recently used half
+///       is evicted (and starts full again if used after), so rotating keys cannot grow the memory without bound.
+pub struct RateLimit<K = ()> {
+	capacity: u32,
+	period: Duration,
+	key_fn: fn(&Resources) -> Option<K>,
+	/// The buckets by route name (None for the calls without a matching route), then by key.
+	buckets: Mutex<HashMap<Option<Arc<str>>, MethodBuckets<K>>>,
+}
+
+/// The buckets of a method, by key.
+type MethodBuckets<K> = HashMap<Option<K>, TokenBucket>;
+
+// -- Constructors
+impl RateLimit<()> {
+	/// Rate limit of `capacity` calls per `period`, per method.
+	///
+	/// Note: A `capacity` of 0 is treated as 1.
+	pub fn new(capacity: u32, period: Duration) -> Self {
+		Self {
+			capacity: capacity.max(1),
+			period,
+			key_fn: |_| None,
+			buckets: Default::default(),
+		}
+	}
+}
+
+impl<K> RateLimit<K> {
+	/// Returns this rate limit keyed by the `T` resource of the call (in addition to the method).
+	pub fn keyed_by<T>(self) -> RateLimit<T>
+	where
+		T: Clone + Hash + Eq + Send + Sync + 'static,
+	{
+		RateLimit {
+			capacity: self.capacity,
+			period: self.period,
+			key_fn: |resources| resources.get::<T>(),
+			buckets: Default::default(),
+		}
+	}
+}
+
+// -- Privates
+impl<K> RateLimit<K>
+where
+	K: Hash + Eq,
+{
+	/// Takes a token from the bucket of this method and key,
+	/// or returns the duration to wait for the next token.
+	fn try_acquire(&self, method: Option<Arc<str>>, key: Option<K>) -> Result<(), Duration> {
+		let now = Instant::now();
+		let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+		let method_buckets = buckets.entry(method).or_default();
+
+		if method_buckets.len() >= MAX_BUCKETS && !method_buckets.contains_key(&key) {
+			evict_least_recently_used(method_buckets);
+		}
+
+		method_buckets
+			.entry(key)
+			.or_insert_with(|| TokenBucket::new(now, self.capacity))
+			.try_take(now, self.capacity, self.period)
+	}
+}
+
+impl<K> Layer for RateLimit<K>
+where
+	K: Hash + Eq + Send + Sync + 'static,
+{
+	fn call<'a>(&'a self, call: LayerCall, next: Next<'a>) -> LayerFuture<'a> {
+		let key = (self.key_fn)(&call.resources);
+		let method = next.route_name(&call).cloned();
+
+		match self.try_acquire(method, key) {
+			Ok(()) => next.run(call),
+			Err(retry_after) => Box::pin(ready(Err(CallError {
+				id: call.id,
+				method: call.method,
+				error: Error::RateLimited(retry_after),
+			}))),
+		}
+	}
+}
+
+// region:    --- TokenBucket
+
+struct TokenBucket {
+	tokens: f64,
+	updated: Instant,
+}
+
+impl TokenBucket {
+	fn new(now: Instant, capacity: u32) -> Self {
+		Self {
+			tokens: capacity as f64,
+			updated: now,
+		}
+	}
+
+	/// Returns the tokens of the bucket at `now` (refilled since the last update).
+	fn tokens_at(&self, now: Instant, capacity: u32, period: Duration) -> f64 {
+		let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+		let refill = elapsed * capacity as f64 / period.as_secs_f64();
+		(self.tokens + refill).min(capacity as f64)
+	}
+
+	fn try_take(&mut self, now: Instant, capacity: u32, period: Duration) -> Result<(), Duration> {
+		self.tokens = self.tokens_at(now, capacity, period);
+		self.updated = now;
+
+		if self.tokens >= 1. {
+			self.tokens -= 1.;
+			Ok(())
+		} else {
+			let missing = 1. - self.tokens;
+			Err(period.mul_f64(missing / capacity as f64))
+		}
+	}
+}
+
+/// Evicts the least recently updated half of the buckets, so the eviction cost (linear in the number of buckets)
+/// is amortized over the next `MAX_BUCKETS / 2` new keys.
+fn evict_least_recently_used<K>(buckets: &mut MethodBuckets<K>) {
+	let mut updates: Vec<Instant> = buckets.values().map(|bucket| bucket.updated).collect();
+	let (_, median, _) = updates.select_nth_unstable(buckets.len() / 2);
+	let median = *median;
+
+	buckets.retain(|_, bucket| bucket.updated > median);
+}
+
+// endregion: --- TokenBucket
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_token_bucket_refill() {
+		// -- Setup & Fixtures
+		let now = Instant::now();
+		let period = Duration::from_secs(1);
+		let mut bucket = TokenBucket::new(now, 2);
+
+		// -- Exec & Check
+		assert!(bucket.try_take(now, 2, period).is_ok());
+		assert!(bucket.try_take(now, 2, period).is_ok());
+		let retry_after = bucket.try_take(now, 2, period).err();
+		assert_eq!(retry_after, Some(Duration::from_millis(500)));
+		assert!(bucket.try_take(now + Duration::from_millis(500), 2, period).is_ok());
+	}
+
+	#[test]
+	fn test_rate_limit_evict_least_recently_used() {
+		// -- Setup & Fixtures
+		let rate_limit = RateLimit::new(1, Duration::from_secs(60)).keyed_by::<usize>();
+
+		// -- Exec
+		for key in 0..MAX_BUCKETS {
+			let _ = rate_limit.try_acquire(None, Some(key));
+		}
+		// the key 1 is used again, so it is among the most recently used
+		let key_1_res = rate_limit.try_acquire(None, Some(1));
+		let new_key_res = rate_limit.try_acquire(None, Some(MAX_BUCKETS));
+		let key_0_res = rate_limit.try_acquire(None, Some(0));
+		let key_1_again_res = rate_limit.try_acquire(None, Some(1));
+
+		// -- Check
+		assert!(key_1_res.is_err());
+		assert!(new_key_res.is_ok());
+		assert!(key_0_res.is_ok(), "key 0 should have been evicted, so full again");
+		assert!(key_1_again_res.is_err(), "key 1 should have been kept");
+		let buckets = rate_limit.buckets.lock().unwrap();
+		assert!(buckets.get(&None).map(|buckets| buckets.len()) <= Some(MAX_BUCKETS / 2 + 2));
+	}
+}
+
+// endregion: --- Tests
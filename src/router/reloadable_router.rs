@@ -0,0 +1,83 @@
+use crate::{CallResult, Router, RouterBuildError, RouterBuilder, RpcRequest};
+use std::sync::{Arc, RwLock};
+
+/// A shareable handle on a `Router` which can be swapped at runtime (e.g., to register and unregister
+/// methods of plugins), without redistributing the router to every connection task.
+///
+/// - Each call takes a snapshot of the current `Router` (an `Arc` clone), so the in-flight calls
+///   finish on the route table and base resources they started with.
+/// - Updates replace the whole `Router` atomically (route table and base resources).
+///
+/// ```
+/// let reloadable_router = ReloadableRouter::new(Router::builder().append("get_task", get_task).build());
+///
+/// // Give a clone to each connection task, and call it as a router.
+/// let res = reloadable_router.call(rpc_request).await;
+///
+/// // Register and unregister methods at runtime.
+/// reloadable_router.update(|builder| builder.append("plugin.run", plugin_run).remove("get_task"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReloadableRouter {
+	current: Arc<RwLock<Router>>,
+}
+
+// -- Constructor
+impl ReloadableRouter {
+	pub fn new(router: Router) -> Self {
+		Self {
+			current: Arc::new(RwLock::new(router)),
+		}
+	}
+}
+
+impl From<Router> for ReloadableRouter {
+	fn from(router: Router) -> Self {
+		Self::new(router)
+	}
+}
+
+// -- Snapshot & Swap
+impl ReloadableRouter {
+	/// Returns the current `Router` (cheap `Arc` clone).
+	///
+	/// The returned router is not affected by later swaps or updates.
+	pub fn router(&self) -> Router {
+		// Note: The lock only guards the swap of the `Router` value, so a poisoned lock still holds a valid router.
+		self.current.read().unwrap_or_else(|err| err.into_inner()).clone()
+	}
+
+	/// Replaces the current `Router`, and returns the previous one.
+	pub fn swap(&self, router: Router) -> Router {
+		let mut current = self.current.write().unwrap_or_else(|err| err.into_inner());
+		std::mem::replace(&mut *current, router)
+	}
+
+	/// Rebuilds the current `Router` with the `update_fn` applied to its `RouterBuilder` (see `Router::to_builder`).
+	///
+	/// The updates are serialized, so concurrent updates do not lose each other's changes.
+	///
+	/// Note: Conflicting registrations are replaced as with `RouterBuilder::build`. Use `try_update` to reject them.
+	pub fn update(&self, update_fn: impl FnOnce(RouterBuilder) -> RouterBuilder) {
+		let mut current = self.current.write().unwrap_or_else(|err| err.into_inner());
+		*current = update_fn(current.to_builder()).build();
+	}
+
+	/// Same as `update`, but uses `RouterBuilder::try_build`, and keeps the current `Router`
+	/// if the updated builder has conflicting registrations.
+	pub fn try_update(&self, update_fn: impl FnOnce(RouterBuilder) -> RouterBuilder) -> Result<(), RouterBuildError> {
+		let mut current = self.current.write().unwrap_or_else(|err| err.into_inner());
+		*current = update_fn(current.to_builder()).try_build()?;
+		Ok(())
+	}
+}
+
+// -- Methods
+impl ReloadableRouter {
+	/// Performs the RPC call with the current `Router` (see `Router::call`).
+	///
+	/// For the other `Router` methods, use `.router()` to get the current `Router`.
+	pub async fn call(&self, rpc_request: RpcRequest) -> CallResult {
+		self.router().call(rpc_request).await
+	}
+}
@@ -0,0 +1,182 @@
+use crate::Guard;
+use crate::handler::RpcHandlerWrapperTrait;
+use crate::router::layer::{Layer, LayerCall};
+use crate::router::metrics::MethodRecorder;
+use crate::{CallError, CallResult, CallSuccess, RouteMeta};
+#[cfg(feature = "tokio")]
+use crate::{ConcurrencyLimit, Error};
+use std::sync::Arc;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+/// A route entry of the `RouterInner` route table.
+///
+/// - `name` - The method name of the route (set when inserted in the route table, and shared with the calls).
+/// - `handler` - The type erased handler function (shared, so the route table can be cloned, see `Router::to_builder`).
+/// - `meta` - The route metadata (shared with the `RouteInfo`).
+/// - `order` - The registration order in the router (see `RouteInfo::order`).
+/// - `timeout` - The route timeout, overriding the router default timeout (`tokio` feature).
+/// - `concurrency_limit` - The maximum number of concurrent handler executions (`tokio` feature).
+/// - `guards` - The route scoped guards (e.g., from `RouterBuilder::method_guard` or a nested `RouterBuilder`),
+///   checked after the router guards.
+/// - `layers` - The route scoped layers (e.g., from a nested `RouterBuilder`), executed after the router
+///   layers, the first one being the outermost.
+/// - `recorder` - The metrics recorder of the route method (resolved at router build when the metrics are enabled).
+#[derive(Clone)]
+pub(crate) struct Route {
+	name: Arc<str>,
+	handler: Arc<dyn RpcHandlerWrapperTrait>,
+	meta: Arc<RouteMeta>,
+	order: usize,
+	#[cfg(feature = "tokio")]
+	timeout: Option<Duration>,
+	#[cfg(feature = "tokio")]
+	concurrency_limit: Option<ConcurrencyLimit>,
+	guards: Vec<Arc<dyn Guard>>,
+	layers: Vec<Arc<dyn Layer>>,
+	recorder: Option<Arc<MethodRecorder>>,
+}
+
+impl Route {
+	pub fn new(handler: Box<dyn RpcHandlerWrapperTrait>) -> Self {
+		Self {
+			name: Arc::from(""),
+			handler: Arc::from(handler),
+			meta: Arc::default(),
+			order: 0,
+			#[cfg(feature = "tokio")]
+			timeout: None,
+			#[cfg(feature = "tokio")]
+			concurrency_limit: None,
+			guards: Vec::new(),
+			layers: Vec::new(),
+			recorder: None,
+		}
+	}
+
+	pub fn with_meta(mut self, meta: RouteMeta) -> Self {
+		self.meta = Arc::new(meta);
+		self
+	}
+
+	/// Replaces the handler, keeping the route settings (metadata, order, timeout, guards, layers, ...).
+	pub fn set_handler(&mut self, handler: Box<dyn RpcHandlerWrapperTrait>) {
+		self.handler = Arc::from(handler);
+	}
+
+	pub fn name(&self) -> &Arc<str> {
+		&self.name
+	}
+
+	pub fn set_name(&mut self, name: Arc<str>) {
+		self.name = name;
+	}
+
+	pub fn meta(&self) -> &Arc<RouteMeta> {
+		&self.meta
+	}
+
+	pub fn order(&self) -> usize {
+		self.order
+	}
+
+	pub fn set_order(&mut self, order: usize) {
+		self.order = order;
+	}
+
+	#[cfg(feature = "tokio")]
+	pub fn timeout(&self) -> Option<Duration> {
+		self.timeout
+	}
+
+	#[cfg(feature = "tokio")]
+	pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+		self.timeout = timeout;
+	}
+
+	#[cfg(feature = "tokio")]
+	pub fn set_concurrency_limit(&mut self, concurrency_limit: Option<ConcurrencyLimit>) {
+		self.concurrency_limit = concurrency_limit;
+	}
+
+	pub fn guards(&self) -> &[Arc<dyn Guard>] {
+		&self.guards
+	}
+
+	pub fn append_guard(&mut self, guard: Arc<dyn Guard>) {
+		self.guards.push(guard);
+	}
+
+	/// Adds the given guards before the existing route guards.
+	pub fn prepend_guards(&mut self, guards: &[Arc<dyn Guard>]) {
+		if !guards.is_empty() {
+			self.guards.splice(0..0, guards.iter().cloned());
+		}
+	}
+
+	pub fn layers(&self) -> &[Arc<dyn Layer>] {
+		&self.layers
+	}
+
+	/// Adds the layer after (i.e., inner to) the existing route layers.
+	pub fn append_layer(&mut self, layer: Arc<dyn Layer>) {
+		self.layers.push(layer);
+	}
+
+	/// Adds the given layers before (i.e., outer to) the existing route layers.
+	pub fn prepend_layers(&mut self, layers: &[Arc<dyn Layer>]) {
+		if !layers.is_empty() {
+			self.layers.splice(0..0, layers.iter().cloned());
+		}
+	}
+
+	pub fn recorder(&self) -> Option<&Arc<MethodRecorder>> {
+		self.recorder.as_ref()
+	}
+
+	pub fn set_recorder(&mut self, recorder: Option<Arc<MethodRecorder>>) {
+		self.recorder = recorder;
+	}
+
+	/// Calls the handler of this route, without any layer.
+	///
+	/// If the route has a concurrency limit, the permit is held for the duration of the handler execution.
+	pub async fn call_handler(&self, call: LayerCall) -> CallResult {
+		let LayerCall {
+			id,
+			method,
+			params,
+			resources,
+			..
+		} = call;
+
+		#[cfg(feature = "tokio")]
+		let _permit = match &self.concurrency_limit {
+			Some(concurrency_limit) => match concurrency_limit.acquire().await {
+				Ok(permit) => Some(permit),
+				Err(error) => return Err(CallError { id, method, error }),
+			},
+			None => None,
+		};
+
+		match self.handler.call(resources, params).await {
+			Ok(value) => Ok(CallSuccess { id, method, value }),
+			Err(error) => Err(CallError { id, method, error }),
+		}
+	}
+
+	/// Calls the handler of this route, and returns an `Error::Timeout` if it does not complete within `timeout`.
+	#[cfg(feature = "tokio")]
+	pub async fn call_handler_with_timeout(&self, call: LayerCall, timeout: Duration) -> CallResult {
+		let (id, method) = (call.id.clone(), call.method.clone());
+
+		match tokio::time::timeout(timeout, self.call_handler(call)).await {
+			Ok(call_result) => call_result,
+			Err(_) => Err(CallError {
+				id,
+				method,
+				error: Error::Timeout(timeout),
+			}),
+		}
+	}
+}
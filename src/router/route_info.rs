@@ -0,0 +1,28 @@
+use crate::RouteMeta;
+use std::sync::Arc;
+
+/// The kind of a route, which defines how it can be called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteKind {
+	/// Route callable by requests (and notifications).
+	Request,
+	/// Notification-only route (see `RouterBuilder::append_notification`).
+	Notification,
+}
+
+/// Public information about a route of a built `Router` (see `Router::routes()`).
+///
+/// This is a snapshot, and can be used for capability listings, admin pages, or startup sanity checks.
+#[derive(Debug, Clone)]
+pub struct RouteInfo {
+	/// The full method name (including the eventual nest prefixes).
+	pub name: Arc<str>,
+	/// The registration order of the route in the router (starting at 0).
+	///
+	/// Note: Routes of extended or nested builders are ordered after the routes
+	///       already registered at the time of the `extend`/`nest`.
+	pub order: usize,
+	pub kind: RouteKind,
+	/// The metadata attached at registration time (see `RouterBuilder::append_with_meta`).
+	pub meta: Arc<RouteMeta>,
+}
@@ -0,0 +1,51 @@
+use crate::ResourcesInner;
+
+/// Typed metadata attached to a route at registration time (see `RouterBuilder::append_with_meta`),
+/// for example, required auth scopes, read-only or idempotent markers, cost weights, or doc strings.
+///
+/// The metadata is a type map (one value per type), readable by the layers (`Next::route_meta`),
+/// the introspection (`RouteInfo::meta`), and the schema generation.
+///
+/// ```
+/// #[derive(Clone)]
+/// pub struct Scopes(pub Vec<&'static str>);
+/// #[derive(Clone)]
+/// pub struct ReadOnly;
+///
+/// let router = Router::builder()
+///     .append_with_meta("list_tasks", list_tasks, RouteMeta::new().with(ReadOnly).with(Scopes(vec!["task:read"])))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RouteMeta {
+	inner: ResourcesInner,
+}
+
+impl RouteMeta {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns this metadata with `val` (replacing the eventual value of the same type).
+	pub fn with<T: Clone + Send + Sync + 'static>(mut self, val: T) -> Self {
+		self.inner.insert(val);
+		self
+	}
+
+	/// Inserts `val`, and returns the eventual previous value of the same type.
+	pub fn insert<T: Clone + Send + Sync + 'static>(&mut self, val: T) -> Option<T> {
+		self.inner.insert(val)
+	}
+
+	pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+		self.inner.get::<T>()
+	}
+
+	pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+		self.get::<T>().is_some()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+}
@@ -0,0 +1,88 @@
+use crate::Handler;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use std::fmt;
+
+/// The params and result JSON Schemas of a route handler (`schemars` feature),
+/// used for the OpenRPC document generation (see `Router::openrpc`).
+///
+/// The `RouteSchema` is a route metadata, typically added with `RouterBuilder::append_with_schema`,
+/// or with `RouteSchema::from_handler` when other metadata is needed.
+///
+/// ```
+/// #[derive(Deserialize, JsonSchema, RpcParams)]
+/// pub struct ParamsIded { id: i64 }
+///
+/// #[derive(Serialize, JsonSchema)]
+/// pub struct Task { id: i64, title: String }
+///
+/// pub async fn get_task(mm: ModelManager, params: ParamsIded) -> HandlerResult<Task> { .. }
+///
+/// let router = Router::builder()
+///     .append_with_schema("get_task", get_task)
+///     // or, with other metadata
+///     .append_with_meta("get_task", get_task, RouteMeta::new().with(RouteSchema::from_handler(&get_task)).with(ReadOnly))
+///     .build();
+/// ```
+#[derive(Clone, Copy)]
+pub struct RouteSchema {
+	params_fn: fn(&mut SchemaGenerator) -> Option<Schema>,
+	result_fn: fn(&mut SchemaGenerator) -> Schema,
+}
+
+impl RouteSchema {
+	/// Returns the `RouteSchema` of the handler `IntoParams` type and return type
+	/// (the handler value is only used for the type inference).
+	pub fn from_handler<F, T, P, R>(_handler: &F) -> Self
+	where
+		F: Handler<T, P, R>,
+		T: Send + Sync + 'static,
+		P: ParamsSchema + Send + Sync + 'static,
+		R: JsonSchema + Send + Sync + 'static,
+	{
+		Self {
+			params_fn: P::params_schema,
+			result_fn: |generator| generator.subschema_for::<R>(),
+		}
+	}
+
+	/// Returns the inline JSON Schema of the params (`None` if the handler has no params).
+	pub fn params_schema(&self, generator: &mut SchemaGenerator) -> Option<Schema> {
+		(self.params_fn)(generator)
+	}
+
+	/// Returns the JSON Schema of the result (a `$ref` for the named types).
+	pub fn result_schema(&self, generator: &mut SchemaGenerator) -> Schema {
+		(self.result_fn)(generator)
+	}
+}
+
+impl fmt::Debug for RouteSchema {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("RouteSchema(..)")
+	}
+}
+
+// region:    --- ParamsSchema
+
+/// The params JSON Schema of the `P` generic of `Handler<T, P, R>`,
+/// which is `(P,)` for handlers with an `IntoParams` argument, and `()` for the ones without.
+pub trait ParamsSchema {
+	fn params_schema(generator: &mut SchemaGenerator) -> Option<Schema>;
+}
+
+impl ParamsSchema for () {
+	fn params_schema(_generator: &mut SchemaGenerator) -> Option<Schema> {
+		None
+	}
+}
+
+impl<P> ParamsSchema for (P,)
+where
+	P: JsonSchema,
+{
+	fn params_schema(generator: &mut SchemaGenerator) -> Option<Schema> {
+		Some(P::json_schema(generator))
+	}
+}
+
+// endregion: --- ParamsSchema
@@ -1,6 +1,8 @@
-use crate::router::router_inner::RouterInner;
-use crate::{CallResult, ResourcesInner, RouterBuilder, RpcRequest};
-use crate::{FromResources, Resources, RpcId};
+use crate::router::router_inner::{RouterInner, parsing_error_response_value};
+use crate::{
+	CallResult, ResourcesInner, RouteInfo, RouterBuilder, RouterMetrics, RpcBatchRequest, RpcBatchResponse, RpcRequest,
+};
+use crate::{FromResources, Resources, RpcId, RpcNotification, RpcRequestParsingError};
 use serde_json::Value;
 use std::sync::Arc;
 
@@ -20,6 +22,22 @@ impl Router {
 }
 impl FromResources for Router {}
 
+// -- To Builder
+impl Router {
+	/// Returns a new `RouterBuilder` with the routes, layers, fallback, policies, and base resources of this router,
+	/// to build a modified router (e.g., adding or removing routes at runtime, see `ReloadableRouter`).
+	///
+	/// Note: The handlers and layers are shared (not cloned), and this router is not modified.
+	pub fn to_builder(&self) -> RouterBuilder {
+		let mut inner = (*self.inner).clone();
+		inner.clear_build_conflicts();
+		// The built-in routes are registered again by the next build.
+		inner.remove_builtin_routes();
+
+		RouterBuilder::from_parts(inner, self.base_resources.to_base_inner())
+	}
+}
+
 // -- Methods
 impl Router {
 	/// Performs the RPC call for a given RpcRequest object (i.e., `.id, .method, .params`)
@@ -50,6 +68,112 @@ impl Router {
 		self.inner.call(resources, rpc_request).await
 	}
 
+	/// Executes the handler matching the notification method with the eventual resources of the router.
+	///
+	/// To add additional resources on top of the router's resources, call `.notify_with_resources(notification, resources)`
+	///
+	/// - Notification-only handlers (`RouterBuilder::append_notification`) take precedence,
+	///   otherwise, the request handler of the same name is called.
+	/// - Notifications do not produce a response. Errors (unknown method, params, handler error)
+	///   are given to the router `NotificationErrorPolicy` (silently dropped by default).
+	pub async fn notify(&self, notification: RpcNotification) {
+		self.inner.notify(self.base_resources.clone(), notification).await
+	}
+
+	/// Similar to `.notify(...)`, but takes an additional `Resources` parameter that will be overlaid on top
+	/// of the eventual base router resources.
+	pub async fn notify_with_resources(&self, notification: RpcNotification, additional_resources: Resources) {
+		let resources = self.compute_call_resources(additional_resources);
+
+		self.inner.notify(resources, notification).await
+	}
+
+	/// Performs all the calls of a JSON-RPC batch (concurrently) with the eventual resources of the router.
+	///
+	/// To add additional resources on top of the router's resources, call `.call_batch_with_resources(batch_request, resources)`
+	///
+	/// - Returns the `RpcBatchResponse` with one `RpcResponse` per request of the batch (in the batch order).
+	/// - Notifications are executed, but do not get a response.
+	/// - Invalid batch items get a `-32600 Invalid Request` error response with a `null` id.
+	/// - Returns `None` when there is nothing to respond (i.e., the batch had only notifications),
+	///   in which case, per the spec, the transport should not send anything back.
+	///
+	/// Note: An empty batch cannot be constructed (`RpcBatchRequest::from_value` returns `RpcRequestParsingError::BatchEmpty`),
+	///       which the transport should respond with the single `RpcError::from(&parsing_error)` (`-32600`) response.
+	pub async fn call_batch(&self, batch_request: RpcBatchRequest) -> Option<RpcBatchResponse> {
+		self.inner.call_batch(self.base_resources.clone(), batch_request).await
+	}
+
+	/// Similar to `.call_batch(...)`, but takes an additional `Resources` parameter that will be overlaid on top
+	/// of the eventual base router resources for every call of the batch.
+	pub async fn call_batch_with_resources(
+		&self,
+		batch_request: RpcBatchRequest,
+		additional_resources: Resources,
+	) -> Option<RpcBatchResponse> {
+		let resources = self.compute_call_resources(additional_resources);
+
+		self.inner.call_batch(resources, batch_request).await
+	}
+
+	/// Handles any raw incoming JSON-RPC message and returns the JSON-RPC response value to send back, if any.
+	/// This is the typical entry point for transports (HTTP, WebSocket, stdio, ...).
+	///
+	/// - Request (object with `id`): returns `Some` response (success or error).
+	/// - Notification (object without `id`): executed, returns `None`.
+	/// - Batch (array): returns `Some` array of responses, or `None` if the batch only had notifications.
+	/// - Invalid message: returns `Some` error response with a `null` id,
+	///   with `-32600 Invalid Request` (or `-32700 Parse error` for `.handle_str`/`.handle_slice` invalid JSON).
+	///
+	/// To add additional resources on top of the router's resources, call `.handle_value_with_resources(value, resources)`
+	pub async fn handle_value(&self, value: Value) -> Option<Value> {
+		self.inner.handle_value(self.base_resources.clone(), value).await
+	}
+
+	/// Similar to `.handle_value(...)`, but takes an additional `Resources` parameter that will be overlaid on top
+	/// of the eventual base router resources.
+	pub async fn handle_value_with_resources(&self, value: Value, additional_resources: Resources) -> Option<Value> {
+		let resources = self.compute_call_resources(additional_resources);
+
+		self.inner.handle_value(resources, value).await
+	}
+
+	/// Same as `.handle_value(...)` but parses the JSON string first.
+	/// Invalid JSON is responded with a `-32700 Parse error`.
+	pub async fn handle_str(&self, json: &str) -> Option<Value> {
+		match serde_json::from_str(json) {
+			Ok(value) => self.handle_value(value).await,
+			Err(err) => Some(parsing_error_response_value(RpcRequestParsingError::Parse(err))),
+		}
+	}
+
+	/// Similar to `.handle_str(...)`, but takes an additional `Resources` parameter that will be overlaid on top
+	/// of the eventual base router resources.
+	pub async fn handle_str_with_resources(&self, json: &str, additional_resources: Resources) -> Option<Value> {
+		match serde_json::from_str(json) {
+			Ok(value) => self.handle_value_with_resources(value, additional_resources).await,
+			Err(err) => Some(parsing_error_response_value(RpcRequestParsingError::Parse(err))),
+		}
+	}
+
+	/// Same as `.handle_value(...)` but parses the JSON bytes first.
+	/// Invalid JSON is responded with a `-32700 Parse error`.
+	pub async fn handle_slice(&self, json: &[u8]) -> Option<Value> {
+		match serde_json::from_slice(json) {
+			Ok(value) => self.handle_value(value).await,
+			Err(err) => Some(parsing_error_response_value(RpcRequestParsingError::Parse(err))),
+		}
+	}
+
+	/// Similar to `.handle_slice(...)`, but takes an additional `Resources` parameter that will be overlaid on top
+	/// of the eventual base router resources.
+	pub async fn handle_slice_with_resources(&self, json: &[u8], additional_resources: Resources) -> Option<Value> {
+		match serde_json::from_slice(json) {
+			Ok(value) => self.handle_value_with_resources(value, additional_resources).await,
+			Err(err) => Some(parsing_error_response_value(RpcRequestParsingError::Parse(err))),
+		}
+	}
+
 	/// Lower level function to `.call` which take all Rpc Request properties as value.
 	/// If id is None, it will be set a Value::Null
 	///
@@ -63,7 +187,7 @@ impl Router {
 	///
 	/// Returns an CallResult, where either the success value (CallSuccess) or the error (CallError)
 	/// will echo back the `id` and `method` part of their construct
-	pub async fn call_route(&self, id: Option<RpcId>, method: impl Into<String>, params: Option<Value>) -> CallResult {
+	pub async fn call_route(&self, id: Option<RpcId>, method: impl AsRef<str>, params: Option<Value>) -> CallResult {
 		let id = id.unwrap_or_default(); // Default to RpcId::Null if None
 		self.inner.call_route(self.base_resources.clone(), id, method, params).await
 	}
@@ -76,7 +200,7 @@ impl Router {
 	pub async fn call_route_with_resources(
 		&self,
 		id: Option<RpcId>,
-		method: impl Into<String>,
+		method: impl AsRef<str>,
 		params: Option<Value>,
 		additional_resources: Resources,
 	) -> CallResult {
@@ -87,6 +211,51 @@ impl Router {
 	}
 }
 
+// -- Introspection
+impl Router {
+	/// Returns the method names callable by requests, in registration order.
+	///
+	/// Note: Notification-only routes are not included (see `.routes()`).
+	pub fn methods(&self) -> Vec<&str> {
+		self.inner.methods()
+	}
+
+	/// Returns true if the router has a route callable by requests for this method name.
+	pub fn has_method(&self, name: &str) -> bool {
+		self.inner.has_method(name)
+	}
+
+	/// Returns the information of all the routes (including notification-only ones), in registration order.
+	pub fn routes(&self) -> Vec<RouteInfo> {
+		self.inner.routes()
+	}
+
+	/// Returns the information of the route for this method name, if any.
+	pub fn route_info(&self, name: &str) -> Option<RouteInfo> {
+		self.inner.route_info(name)
+	}
+}
+
+// -- Metrics
+impl Router {
+	/// Returns the snapshot of the per-method metrics (call and error counts, in-flight calls, and latency histograms),
+	/// or None if the metrics are not enabled (see `RouterBuilder::enable_metrics`).
+	pub fn metrics(&self) -> Option<RouterMetrics> {
+		self.inner.metrics()
+	}
+}
+
+// -- OpenRPC
+#[cfg(feature = "schemars")]
+impl Router {
+	/// Returns the OpenRPC document (<https://spec.open-rpc.org>) describing the request methods of this router,
+	/// with the params and result schemas of the routes having a `RouteSchema` metadata
+	/// (see `RouterBuilder::append_with_schema`).
+	pub fn openrpc(&self, info: &crate::OpenRpcInfo) -> Value {
+		crate::router::openrpc::openrpc_document(&self.inner, info)
+	}
+}
+
 // Crate only method
 impl Router {
 	/// For specific or advanced use cases.
@@ -98,9 +267,15 @@ impl Router {
 	/// Note: This is intended for situations where a custom builder
 	///       workflow is needed. The recommended method for creating an `Router`
 	///       is via the `RouterBuilder`.
-	pub(crate) fn new(inner: RouterInner, resources_inner: ResourcesInner) -> Self {
+	pub(crate) fn new(mut inner: RouterInner, resources_inner: ResourcesInner) -> Self {
 		Self {
-			inner: Arc::new(inner),
+			// Note: The built-in methods (e.g., `rpc.methods`) need a reference to their route table.
+			inner: Arc::new_cyclic(|inner_weak| {
+				inner.append_builtin_routes(inner_weak);
+				inner.resolve_route_recorders();
+				inner.build_static_table();
+				inner
+			}),
 			base_resources: Resources::from_base_inner(resources_inner),
 		}
 	}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+/// The Error returned by `RouterBuilder::try_build()` when the builder has conflicting registrations.
+///
+/// - `duplicate_methods` - The method names registered more than once (e.g., `append` twice, or merged by
+///   `extend`/`nest`), in detection order. Notification-only routes do not conflict with request routes.
+/// - `reserved_methods` - The method names starting with the `rpc.` prefix, reserved by the JSON-RPC specification
+///   (see `RouterBuilder::rpc_ping`, `rpc_methods`, `rpc_discover`, and `rpc_metrics` for the built-in ones).
+/// - `duplicate_resources` - The type names of the base resources provided more than once
+///   by `extend_resources`, `extend`, or `nest`.
+/// - `unknown_methods` - The method names given to `method_guard`, `method_layer`, `method_timeout`,
+///   or `method_concurrency_limit` without a matching route (e.g., misspelled, or not appended yet).
+///
+/// Intentional replacements do not conflict (see `RouterBuilder::replace` and `RouterBuilder::append_resource`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouterBuildError {
+	pub duplicate_methods: Vec<Arc<str>>,
+	pub reserved_methods: Vec<Arc<str>>,
+	pub duplicate_resources: Vec<&'static str>,
+	pub unknown_methods: Vec<Arc<str>>,
+}
+
+impl RouterBuildError {
+	pub fn is_empty(&self) -> bool {
+		self.duplicate_methods.is_empty()
+			&& self.reserved_methods.is_empty()
+			&& self.duplicate_resources.is_empty()
+			&& self.unknown_methods.is_empty()
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for RouterBuildError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for RouterBuildError {}
+
+// endregion: --- Error Boilerplate
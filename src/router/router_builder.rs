@@ -1,25 +1,40 @@
+#[cfg(feature = "tokio")]
+use crate::ConcurrencyLimit;
 use crate::handler::RpcHandlerWrapperTrait;
 use crate::router::router_inner::RouterInner;
-use crate::{FromResources, Handler, ResourcesBuilder, ResourcesInner, Router};
+use crate::{
+	CallError, FromResources, Guard, Handler, Layer, NotificationErrorPolicy, ResourcesBuilder, ResourcesInner,
+	RouteMeta, Router, RouterBuildError, StaticLookup, TraceContextLocation,
+};
+use std::sync::Arc;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
 
 #[derive(Debug, Default)]
 pub struct RouterBuilder {
 	inner: RouterInner,
 	base_resources_inner: ResourcesInner,
+	/// The type names of the base resources provided more than once (see `try_build`).
+	duplicate_resources: Vec<&'static str>,
 }
 
 impl RouterBuilder {
+	/// The default separator between the prefix and the method name for `.nest(prefix, sub_builder)`.
+	pub const DEFAULT_NEST_SEPARATOR: &'static str = ".";
+
 	/// Add a dyn_handler to the router builder.
 	///
 	/// ```
 	/// RouterBuilder::default().add_dyn("method_name", my_handler_fn.into_dyn());
 	/// ```
 	///
+	/// The `name` can be a `&'static str` or a name computed at runtime (e.g., `String`, `Arc<str>`).
+	///
 	/// Note: This is the preferred way to add handlers to the router, as it
 	///       avoids monomorphization of the add function.
 	///       The `RouterInner` also has a `.add()` as a convenience function to just pass the function.
 	///       See `RouterInner::add` for more details.
-	pub fn append_dyn(mut self, name: &'static str, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) -> Self {
+	pub fn append_dyn(mut self, name: impl Into<Arc<str>>, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) -> Self {
 		self.inner.append_dyn(name, dyn_handler);
 		self
 	}
@@ -33,7 +48,7 @@ impl RouterBuilder {
 	/// Note: This is a convenient add function variant with generics,
 	///       and there will be monomorphed versions of this function
 	///       for each type passed. Use `RouterInner::add_dyn` to avoid this.
-	pub fn append<F, T, P, R>(mut self, name: &'static str, handler: F) -> Self
+	pub fn append<F, T, P, R>(mut self, name: impl Into<Arc<str>>, handler: F) -> Self
 	where
 		F: Handler<T, P, R> + Clone + Send + Sync + 'static,
 		T: Send + Sync + 'static,
@@ -44,13 +59,340 @@ impl RouterBuilder {
 		self
 	}
 
+	/// Add a dyn_handler with its typed route metadata (e.g., auth scopes, read-only marker, doc string).
+	///
+	/// See `append_with_meta` for more details.
+	pub fn append_dyn_with_meta(
+		mut self,
+		name: impl Into<Arc<str>>,
+		dyn_handler: Box<dyn RpcHandlerWrapperTrait>,
+		meta: RouteMeta,
+	) -> Self {
+		self.inner.append_dyn_with_meta(name, dyn_handler, meta);
+		self
+	}
+
+	/// Add a route (name, handler function) with its typed route metadata.
+	///
+	/// ```
+	/// RouterBuilder::default().append_with_meta("list_tasks", list_tasks, RouteMeta::new().with(ReadOnly));
+	/// ```
+	///
+	/// The metadata is available to the layers (`Next::route_meta`) and the introspection (`RouteInfo::meta`).
+	pub fn append_with_meta<F, T, P, R>(mut self, name: impl Into<Arc<str>>, handler: F, meta: RouteMeta) -> Self
+	where
+		F: Handler<T, P, R> + Clone + Send + Sync + 'static,
+		T: Send + Sync + 'static,
+		P: Send + Sync + 'static,
+		R: Send + Sync + 'static,
+	{
+		self.inner.append_dyn_with_meta(name, handler.into_dyn(), meta);
+		self
+	}
+
+	/// Add a route (name, handler function) with its `RouteSchema` metadata (`schemars` feature),
+	/// derived from the handler `IntoParams` type and return type, which must implement `schemars::JsonSchema`.
+	///
+	/// See `Router::openrpc` for the OpenRPC document generation.
+	#[cfg(feature = "schemars")]
+	pub fn append_with_schema<F, T, P, R>(self, name: impl Into<Arc<str>>, handler: F) -> Self
+	where
+		F: Handler<T, P, R> + Clone + Send + Sync + 'static,
+		T: Send + Sync + 'static,
+		P: crate::ParamsSchema + Send + Sync + 'static,
+		R: schemars::JsonSchema + Send + Sync + 'static,
+	{
+		let meta = RouteMeta::new().with(crate::RouteSchema::from_handler(&handler));
+		self.append_with_meta(name, handler, meta)
+	}
+
+	/// Add or replace a dyn_handler, for an intentional replacement of an already registered method.
+	///
+	/// Unlike `append_dyn`, replacing an existing method is not reported as a duplicate by `try_build`,
+	/// and the replaced route keeps its settings (metadata, timeout, concurrency limit, method guards and layers,
+	/// and the guards and layers of a `nest`), only its handler is replaced.
+	///
+	/// Note: Appending an existing method (a duplicate reported by `try_build`) replaces the whole route,
+	///       dropping the settings of the previous one.
+	pub fn replace_dyn(mut self, name: impl Into<Arc<str>>, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) -> Self {
+		self.inner.replace_dyn(name, dyn_handler);
+		self
+	}
+
+	/// Add or replace a route (name, handler function), for an intentional replacement of an already registered method.
+	///
+	/// See `replace_dyn` for more details.
+	pub fn replace<F, T, P, R>(mut self, name: impl Into<Arc<str>>, handler: F) -> Self
+	where
+		F: Handler<T, P, R> + Clone + Send + Sync + 'static,
+		T: Send + Sync + 'static,
+		P: Send + Sync + 'static,
+		R: Send + Sync + 'static,
+	{
+		self.inner.replace_dyn(name, handler.into_dyn());
+		self
+	}
+
+	/// Removes the route(s) of this name (request and notification-only), if any.
+	///
+	/// This is typically used with `Router::to_builder()` to unregister methods of a live router
+	/// (see `ReloadableRouter::update`).
+	pub fn remove(mut self, name: &str) -> Self {
+		self.inner.remove(name);
+		self
+	}
+
+	/// Add a notification-only dyn_handler to the router builder.
+	///
+	/// Notification handlers are only called by notifications (e.g., `Router::notify`),
+	/// and are not callable as requests. For a notification, a notification-only handler takes precedence
+	/// over the request handler of the same name (requests handlers are also called by notifications).
+	pub fn append_notification_dyn(
+		mut self,
+		name: impl Into<Arc<str>>,
+		dyn_handler: Box<dyn RpcHandlerWrapperTrait>,
+	) -> Self {
+		self.inner.append_notification_dyn(name, dyn_handler);
+		self
+	}
+
+	/// Add a notification-only route (name, handler function) to the builder.
+	///
+	/// See `append_notification_dyn` for more details.
+	pub fn append_notification<F, T, P, R>(mut self, name: impl Into<Arc<str>>, handler: F) -> Self
+	where
+		F: Handler<T, P, R> + Clone + Send + Sync + 'static,
+		T: Send + Sync + 'static,
+		P: Send + Sync + 'static,
+		R: Send + Sync + 'static,
+	{
+		self.inner.append_notification_dyn(name, handler.into_dyn());
+		self
+	}
+
+	/// Sets the dyn_handler called for the requests and notifications without a matching route.
+	///
+	/// See `fallback` for more details.
+	pub fn fallback_dyn(mut self, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) -> Self {
+		self.inner.set_fallback_dyn(dyn_handler);
+		self
+	}
+
+	/// Sets the handler called for the requests and notifications without a matching route,
+	/// instead of returning `Error::MethodUnknown` (e.g., to forward unknown methods to a legacy backend).
+	///
+	/// The fallback is a regular handler, which gets the method name with the `FallbackMethod` resource,
+	/// and the raw params with an `Option<Value>` params argument.
+	///
+	/// ```
+	/// pub async fn fallback(method: FallbackMethod, params: Option<Value>) -> HandlerResult<Value> { .. }
+	///
+	/// let router = Router::builder().append("my_method", my_method).fallback(fallback).build();
+	/// ```
+	///
+	/// Note: The fallback is called after the router layers (as for any route).
+	pub fn fallback<F, T, P, R>(mut self, handler: F) -> Self
+	where
+		F: Handler<T, P, R> + Clone + Send + Sync + 'static,
+		T: Send + Sync + 'static,
+		P: Send + Sync + 'static,
+		R: Send + Sync + 'static,
+	{
+		self.inner.set_fallback_dyn(handler.into_dyn());
+		self
+	}
+
+	/// Sets the default timeout of the calls (requests and notifications), after which the call
+	/// fails with `Error::Timeout` (mapped to the `RpcError::CODE_TIMEOUT` server error).
+	///
+	/// The timeout applies to the handler execution (including the params parsing), and can be
+	/// overridden per method with `method_timeout`.
+	///
+	/// Note: For `extend` and `nest`, the default timeout of the other builder becomes the timeout
+	///       of its routes without their own timeout.
+	#[cfg(feature = "tokio")]
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.inner.set_default_timeout(Some(timeout));
+		self
+	}
+
+	/// Sets the timeout of an already appended method (request and notification-only routes of this name),
+	/// overriding the default timeout.
+	///
+	/// Note: Must be called after the `append...` of this method, otherwise, it has no effect,
+	///       and the name is reported as unknown by `try_build`.
+	#[cfg(feature = "tokio")]
+	pub fn method_timeout(mut self, name: &str, timeout: Duration) -> Self {
+		if !self.inner.set_route_timeout(name, timeout) {
+			self.inner.record_unknown_method(name);
+		}
+		self
+	}
+
+	/// Sets the concurrency limit of an already appended method (request and notification-only routes of this name),
+	/// with either queueing or immediate rejection (`Error::ConcurrencyLimitReached`) of the calls over the limit.
+	///
+	/// ```
+	/// let router = Router::builder()
+	///     .append("generate_report", generate_report)
+	///     .method_concurrency_limit("generate_report", ConcurrencyLimit::queue(2))
+	///     .build();
+	/// ```
+	///
+	/// Note: Must be called after the `append...` of this method, otherwise, it has no effect,
+	///       and the name is reported as unknown by `try_build`.
+	///       The queued calls count in the eventual call timeout.
+	#[cfg(feature = "tokio")]
+	pub fn method_concurrency_limit(mut self, name: &str, concurrency_limit: ConcurrencyLimit) -> Self {
+		if !self.inner.set_route_concurrency_limit(name, concurrency_limit) {
+			self.inner.record_unknown_method(name);
+		}
+		self
+	}
+
+	/// Enables the per-method metrics of the router, recorded for every call and notification
+	/// (including the method unknown and params parsing errors), and exposed with `Router::metrics`.
+	pub fn enable_metrics(mut self) -> Self {
+		self.inner.enable_metrics();
+		self
+	}
+
+	/// Enables the built-in `rpc.ping` method, returning `"pong"` (e.g., for health checks).
+	pub fn rpc_ping(mut self) -> Self {
+		self.inner.builtin_methods_mut().ping = true;
+		self
+	}
+
+	/// Enables the built-in `rpc.methods` method, returning the request method names of the router.
+	pub fn rpc_methods(mut self) -> Self {
+		self.inner.builtin_methods_mut().methods = true;
+		self
+	}
+
+	/// Enables the built-in `rpc.discover` method, returning the OpenRPC document of the router
+	/// (see `Router::openrpc`), for the client tooling (e.g., OpenRPC playground).
+	#[cfg(feature = "schemars")]
+	pub fn rpc_discover(mut self, info: crate::OpenRpcInfo) -> Self {
+		self.inner.builtin_methods_mut().discover = Some(info);
+		self
+	}
+
+	/// Enables the built-in `rpc.metrics` method, returning the router metrics in the Prometheus text format
+	/// (see `RouterMetrics::to_prometheus`), and enables the metrics (see `enable_metrics`).
+	#[cfg(feature = "prometheus")]
+	pub fn rpc_metrics(mut self) -> Self {
+		self.inner.enable_metrics();
+		self.inner.builtin_methods_mut().metrics = true;
+		self
+	}
+
+	/// Enables the extraction of the W3C trace context (`traceparent` and `tracestate`) at this location
+	/// of the raw messages (see `Router::handle_value`, `handle_str`, and `handle_slice`),
+	/// overlaid as a `TraceContext` resource in the call resources.
+	///
+	/// Note: For the already parsed `RpcRequest`, use `RpcRequest::from_value_with_trace_context`
+	///       and `Router::call_with_resources`.
+	pub fn trace_context(mut self, location: TraceContextLocation) -> Self {
+		self.inner.set_trace_context_location(location);
+		self
+	}
+
+	/// Sets the compile-time method lookup of a static router (typically generated by `static_router_builder!`).
+	pub fn static_lookup(mut self, static_lookup: StaticLookup) -> Self {
+		self.inner.set_static_lookup(static_lookup);
+		self
+	}
+
+	/// Sets the policy for notification errors (default `NotificationErrorPolicy::Ignore`).
+	pub fn notification_error_policy(mut self, policy: NotificationErrorPolicy) -> Self {
+		self.inner.set_notification_error_policy(policy);
+		self
+	}
+
+	/// Convenient function to set a `NotificationErrorPolicy::Hook` called on each notification error
+	/// (e.g., for logging).
+	pub fn on_notification_error(self, hook_fn: impl Fn(&CallError) + Send + Sync + 'static) -> Self {
+		self.notification_error_policy(NotificationErrorPolicy::hook(hook_fn))
+	}
+
+	/// Add a `Layer` wrapping every call of the router (requests and notifications).
+	///
+	/// Layers are executed in the order they are added: the first added layer is the outermost one
+	/// (first to see the call, last to see the `CallResult`).
+	///
+	/// For an `extend` or a `nest`, the layers of the other builder only apply to its routes.
+	pub fn layer(mut self, layer: impl Layer) -> Self {
+		self.inner.append_layer(Arc::new(layer));
+		self
+	}
+
+	/// Add a `Layer` wrapping only the calls of an already appended method (request and notification-only routes
+	/// of this name), executed after the layers of the router (e.g., a `RateLimit` for this method).
+	///
+	/// Note: Must be called after the `append...` of this method, otherwise, it has no effect,
+	///       and the name is reported as unknown by `try_build`.
+	pub fn method_layer(mut self, name: &str, layer: impl Layer) -> Self {
+		if !self.inner.append_route_layer(name, Arc::new(layer)) {
+			self.inner.record_unknown_method(name);
+		}
+		self
+	}
+
+	/// Add a `Guard` checked for all the routes of this builder, before the params parsing and handler execution.
+	///
+	/// For an `extend` or a `nest`, the guards of the other builder only apply to its routes
+	/// (e.g., guards per module or per prefix).
+	pub fn guard(mut self, guard: impl Guard) -> Self {
+		self.inner.append_guard(Arc::new(guard));
+		self
+	}
+
+	/// Add a `Guard` checked only for an already appended method (request and notification-only routes of this name),
+	/// after the guards of the router.
+	///
+	/// Note: Must be called after the `append...` of this method, otherwise, it has no effect,
+	///       and the name is reported as unknown by `try_build`.
+	pub fn method_guard(mut self, name: &str, guard: impl Guard) -> Self {
+		if !self.inner.append_route_guard(name, Arc::new(guard)) {
+			self.inner.record_unknown_method(name);
+		}
+		self
+	}
+
 	/// Extends this builder by consuming another builder.
+	///
+	/// Note: The layers and guards of `other_builder` only apply to its routes, and its fallback if this builder
+	///       has none (executed after the layers and guards of this builder, as for `nest`).
 	pub fn extend(mut self, other_builder: RouterBuilder) -> Self {
 		self.inner.extend(other_builder.inner);
-		self.base_resources_inner.extend(other_builder.base_resources_inner);
+		self.duplicate_resources.extend(other_builder.duplicate_resources);
+		self.extend_base_resources_inner(other_builder.base_resources_inner);
 		self
 	}
 
+	/// Extends this builder with the routes of `sub_builder` mounted under `prefix`,
+	/// with the default `.` separator (e.g., `project.list`).
+	///
+	/// See `nest_with_separator` for more details.
+	pub fn nest(self, prefix: &str, sub_builder: RouterBuilder) -> Self {
+		self.nest_with_separator(prefix, Self::DEFAULT_NEST_SEPARATOR, sub_builder)
+	}
+
+	/// Extends this builder with the routes of `sub_builder` mounted under `prefix`,
+	/// as `{prefix}{separator}{method_name}` (e.g., `project/list` for the `/` separator).
+	///
+	/// - The layers of `sub_builder` only apply to its routes (after the layers of this builder).
+	/// - The base resources of `sub_builder` are added to the base resources of this builder (shared by all routes).
+	/// - The notification error policy of `sub_builder` is ignored.
+	pub fn nest_with_separator(mut self, prefix: &str, separator: &str, sub_builder: RouterBuilder) -> Self {
+		self.inner.nest(prefix, separator, sub_builder.inner);
+		self.duplicate_resources.extend(sub_builder.duplicate_resources);
+		self.extend_base_resources_inner(sub_builder.base_resources_inner);
+		self
+	}
+
+	/// Add a base resource, replacing the eventual base resource of the same type
+	/// (this is an explicit replacement, not reported by `try_build`).
 	pub fn append_resource<T>(mut self, val: T) -> Self
 	where
 		T: FromResources + Clone + Send + Sync + 'static,
@@ -63,14 +405,7 @@ impl RouterBuilder {
 	/// If None, just do nothing.
 	pub fn extend_resources(mut self, resources_builder: Option<ResourcesBuilder>) -> Self {
 		if let Some(resources_builder) = resources_builder {
-			// if self resources empty, no need to extend
-			if self.base_resources_inner.is_empty() {
-				self.base_resources_inner = resources_builder.resources_inner
-			}
-			// if not empty, we extend
-			else {
-				self.base_resources_inner.extend(resources_builder.resources_inner)
-			}
+			self.extend_base_resources_inner(resources_builder.resources_inner);
 		}
 		self
 	}
@@ -84,13 +419,63 @@ impl RouterBuilder {
 	///        typically used.
 	pub fn set_resources(mut self, resources_builder: ResourcesBuilder) -> Self {
 		self.base_resources_inner = resources_builder.resources_inner;
+		self.duplicate_resources.clear();
 		self
 	}
 
 	/// Builds the `RpcRouter` from this builder.
 	/// This is the typical usage, with the `RpcRouter` being encapsulated in an `Arc`,
 	/// indicating it is designed for cloning and sharing across tasks/threads.
+	///
+	/// Note: Duplicate methods and base resources are silently replaced by the last one,
+	///       and the per-method settings of unknown methods are ignored.
+	///       Use `try_build` to get them as an error.
 	pub fn build(self) -> Router {
 		Router::new(self.inner, self.base_resources_inner)
 	}
+
+	/// Builds the `Router` from this builder, or returns a `RouterBuildError` listing
+	/// the conflicting method names and base resource types, the methods using the reserved `rpc.` prefix,
+	/// and the per-method settings of unknown methods.
+	///
+	/// Use `replace` / `replace_dyn` (methods) and `append_resource` (resources) for intentional replacements.
+	pub fn try_build(self) -> Result<Router, RouterBuildError> {
+		let error = RouterBuildError {
+			duplicate_methods: self.inner.duplicate_methods().to_vec(),
+			reserved_methods: self.inner.reserved_methods(),
+			duplicate_resources: self.duplicate_resources,
+			unknown_methods: self.inner.unknown_methods().to_vec(),
+		};
+
+		if error.is_empty() {
+			Ok(Router::new(self.inner, self.base_resources_inner))
+		} else {
+			Err(error)
+		}
+	}
+}
+
+// Privates
+impl RouterBuilder {
+	pub(crate) fn from_parts(inner: RouterInner, base_resources_inner: ResourcesInner) -> Self {
+		Self {
+			inner,
+			base_resources_inner,
+			duplicate_resources: Vec::new(),
+		}
+	}
+
+	/// Extends the base resources, and records the types already present as duplicate resources.
+	fn extend_base_resources_inner(&mut self, resources_inner: ResourcesInner) {
+		// if self resources empty, no need to extend
+		if self.base_resources_inner.is_empty() {
+			self.base_resources_inner = resources_inner
+		}
+		// if not empty, we extend
+		else {
+			let duplicates = self.base_resources_inner.common_type_names(&resources_inner);
+			self.duplicate_resources.extend(duplicates);
+			self.base_resources_inner.extend(resources_inner)
+		}
+	}
 }
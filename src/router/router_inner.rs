@@ -1,21 +1,86 @@
+#[cfg(feature = "tokio")]
+use crate::ConcurrencyLimit;
 use crate::handler::RpcHandlerWrapperTrait;
-use crate::{CallError, CallResult, CallSuccess, Error, Resources, RpcId, RpcRequest};
+use crate::router::builtin_methods::BuiltinMethods;
+#[cfg(feature = "tracing")]
+use crate::router::call_span::{call_span, record_outcome};
+use crate::router::layer::{DispatchedRoute, Layer, LayerCall, Next};
+use crate::router::method_interner::MethodInterner;
+use crate::router::metrics::MetricsRegistry;
+use crate::router::route::Route;
+use crate::{
+	CallResult, Guard, GuardCall, GuardRejection, NotificationErrorPolicy, RPC_RESERVED_PREFIX, Resources, RouteInfo,
+	RouteKind, RouteMeta, RouterMetrics, RpcBatchItem, RpcBatchRequest, RpcBatchResponse, RpcError, RpcId,
+	RpcNotification, RpcRequest, RpcRequestParsingError, RpcResponse, StaticLookup, TraceContext, TraceContextLocation,
+};
+use futures::future::join_all;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
 
 /// method, which calls the appropriate handler matching the method_name.
 ///
 /// RouterInner can be extended with other RouterInners for composability.
-#[derive(Default)]
+///
+/// Note: Cloning a RouterInner clones the route table, but shares the handlers and layers.
+#[derive(Default, Clone)]
 pub(crate) struct RouterInner {
-	route_by_name: HashMap<&'static str, Box<dyn RpcHandlerWrapperTrait>>,
+	route_by_name: HashMap<Arc<str>, Route>,
+	/// Notification-only routes (not callable as requests).
+	notification_by_name: HashMap<Arc<str>, Route>,
+	notification_error_policy: NotificationErrorPolicy,
+	/// Layers wrapping every dispatch, the first one being the outermost.
+	layers: Vec<Arc<dyn Layer>>,
+	/// Guards checked for every route, before the route guards.
+	guards: Vec<Arc<dyn Guard>>,
+	/// The registration order of the next route.
+	next_order: usize,
+	/// The method names registered more than once (without `replace_dyn`).
+	duplicate_methods: Vec<Arc<str>>,
+	/// The method names given to a per-method setting (e.g., `method_guard`) without a matching route.
+	unknown_methods: Vec<Arc<str>>,
+	/// The route called when no route matches the method.
+	fallback: Option<Route>,
+	/// The compile-time lookup of the static routers (see `static_router_builder!`).
+	static_lookup: Option<StaticLookup>,
+	/// The routes of the `static_lookup` names, by lookup index (see `build_static_table`).
+	static_routes: Vec<Option<Route>>,
+	/// True when all the request routes are in `static_routes` (no need for the `HashMap` on lookup miss).
+	static_complete: bool,
+	/// The opt-in built-in methods (e.g., `rpc.ping`), registered when the `Router` is built.
+	builtin_methods: BuiltinMethods,
+	/// The location of the W3C trace context extracted from the raw messages (see `RouterBuilder::trace_context`).
+	trace_context_location: Option<TraceContextLocation>,
+	/// The per-method metrics, if enabled (see `RouterBuilder::enable_metrics`).
+	metrics: Option<Arc<MetricsRegistry>>,
+	/// The shared names of the called methods without a route.
+	unknown_method_names: Arc<MethodInterner>,
+	/// The timeout of the routes without their own timeout.
+	#[cfg(feature = "tokio")]
+	default_timeout: Option<Duration>,
 }
 
 impl fmt::Debug for RouterInner {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		f.debug_struct("RouterInner")
 			.field("route_by_name", &self.route_by_name.keys())
+			.field("notification_by_name", &self.notification_by_name.keys())
+			.field("notification_error_policy", &self.notification_error_policy)
+			.field("layers", &self.layers.len())
+			.field("guards", &self.guards.len())
+			.field("duplicate_methods", &self.duplicate_methods)
+			.field("unknown_methods", &self.unknown_methods)
+			.field("fallback", &self.fallback.is_some())
+			.field("builtin_methods", &self.builtin_methods)
+			.field("trace_context_location", &self.trace_context_location)
+			.field("metrics", &self.metrics.is_some())
+			.field(
+				"static_lookup",
+				&self.static_lookup.map(|static_lookup| static_lookup.names),
+			)
 			.finish()
 	}
 }
@@ -31,12 +96,379 @@ impl RouterInner {
 	///       avoids monomorphization of the add function.
 	///       The RouterInner also has a `.add()` as a convenience function to just pass the function.
 	///       See `RouterInner::add` for more details.
-	pub fn append_dyn(&mut self, name: &'static str, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) {
-		self.route_by_name.insert(name, dyn_handler);
+	pub fn append_dyn(&mut self, name: impl Into<Arc<str>>, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) {
+		self.append_route(RouteKind::Request, name.into(), Route::new(dyn_handler));
+	}
+
+	/// Add a dyn_handler with its route metadata.
+	pub fn append_dyn_with_meta(
+		&mut self,
+		name: impl Into<Arc<str>>,
+		dyn_handler: Box<dyn RpcHandlerWrapperTrait>,
+		meta: RouteMeta,
+	) {
+		self.append_route(RouteKind::Request, name.into(), Route::new(dyn_handler).with_meta(meta));
+	}
+
+	/// Add or replace a dyn_handler, without recording a duplicate method if the name is already registered.
+	///
+	/// Note: A replaced route keeps its settings (metadata, order, timeout, concurrency limit, guards, and layers,
+	///       including the ones of a `nest`), only its handler is replaced.
+	pub fn replace_dyn(&mut self, name: impl Into<Arc<str>>, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) {
+		let name = name.into();
+		match self.route_by_name.get_mut(&name) {
+			Some(route) => route.set_handler(dyn_handler),
+			None => {
+				self.insert_route(RouteKind::Request, name, Route::new(dyn_handler));
+			}
+		}
+	}
+
+	/// Add a notification-only dyn_handler to the router.
+	///
+	/// Those handlers are only called for notifications (i.e., `Router::notify`),
+	/// and take precedence over the request handler of the same name.
+	pub fn append_notification_dyn(&mut self, name: impl Into<Arc<str>>, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) {
+		self.append_route(RouteKind::Notification, name.into(), Route::new(dyn_handler));
+	}
+
+	/// Removes the request and notification-only routes of this name.
+	///
+	/// Returns true if a route was removed.
+	pub fn remove(&mut self, name: &str) -> bool {
+		let removed_request = self.route_by_name.remove(name).is_some();
+		let removed_notification = self.notification_by_name.remove(name).is_some();
+		removed_request || removed_notification
+	}
+
+	/// Clears the recorded duplicate and unknown methods (e.g., when a built route table is reused for a new builder).
+	pub fn clear_build_conflicts(&mut self) {
+		self.duplicate_methods.clear();
+		self.unknown_methods.clear();
+	}
+
+	pub fn set_static_lookup(&mut self, static_lookup: StaticLookup) {
+		self.static_lookup = Some(static_lookup);
+	}
+
+	/// Builds the route table by index of the static lookup (if any), once all the routes are registered.
+	pub fn build_static_table(&mut self) {
+		let Some(static_lookup) = self.static_lookup else {
+			self.static_routes.clear();
+			self.static_complete = false;
+			return;
+		};
+
+		self.static_routes = static_lookup
+			.names
+			.iter()
+			.map(|name| self.route_by_name.get(*name).cloned())
+			.collect();
+		self.static_complete = self.static_routes.iter().flatten().count() == self.route_by_name.len();
+	}
+
+	pub fn set_trace_context_location(&mut self, location: TraceContextLocation) {
+		self.trace_context_location = Some(location);
+	}
+
+	/// Enables the per-method metrics (keeping the recorded metrics if already enabled).
+	pub fn enable_metrics(&mut self) {
+		if self.metrics.is_none() {
+			self.metrics = Some(Arc::default());
+		}
+	}
+
+	/// Sets the metrics recorder of each route (or clears them if the metrics are not enabled),
+	/// so the calls do not look up their recorder. Must be called once all the routes are registered.
+	pub fn resolve_route_recorders(&mut self) {
+		let metrics = self.metrics.clone();
+		for route in self.route_by_name.values_mut().chain(self.notification_by_name.values_mut()) {
+			let recorder = metrics.as_ref().map(|metrics| metrics.method_recorder(route.name()));
+			route.set_recorder(recorder);
+		}
+	}
+
+	/// Returns the snapshot of the metrics, if enabled.
+	pub fn metrics(&self) -> Option<RouterMetrics> {
+		self.metrics.as_ref().map(|metrics| metrics.snapshot())
+	}
+
+	pub fn builtin_methods_mut(&mut self) -> &mut BuiltinMethods {
+		&mut self.builtin_methods
+	}
+
+	/// Registers the enabled built-in routes, with `router_inner_weak` being the future `Arc` of this `RouterInner`.
+	pub fn append_builtin_routes(&mut self, router_inner_weak: &std::sync::Weak<RouterInner>) {
+		let builtin_methods = self.builtin_methods.clone();
+		builtin_methods.append_routes(self, router_inner_weak);
+	}
+
+	/// Removes the built-in routes (e.g., before reusing the route table for a new router).
+	pub fn remove_builtin_routes(&mut self) {
+		for name in self.builtin_methods.names() {
+			self.remove(name);
+		}
+	}
+
+	/// Returns the request and notification-only method names starting with the reserved `rpc.` prefix.
+	pub fn reserved_methods(&self) -> Vec<Arc<str>> {
+		self.routes()
+			.into_iter()
+			.map(|route| route.name)
+			.filter(|name| name.starts_with(RPC_RESERVED_PREFIX))
+			.collect()
+	}
+
+	/// Sets the dyn_handler called for the requests and notifications without a matching route.
+	pub fn set_fallback_dyn(&mut self, dyn_handler: Box<dyn RpcHandlerWrapperTrait>) {
+		self.fallback = Some(Route::new(dyn_handler));
+	}
+
+	#[cfg(feature = "tokio")]
+	pub fn set_default_timeout(&mut self, timeout: Option<Duration>) {
+		self.default_timeout = timeout;
+	}
+
+	#[cfg(feature = "tokio")]
+	pub(crate) fn default_timeout(&self) -> Option<Duration> {
+		self.default_timeout
 	}
 
+	/// Sets the timeout of the request and notification-only routes of this name.
+	///
+	/// Returns false if there is no route of this name.
+	#[cfg(feature = "tokio")]
+	pub fn set_route_timeout(&mut self, name: &str, timeout: Duration) -> bool {
+		self.update_routes(name, |route| route.set_timeout(Some(timeout)))
+	}
+
+	/// Sets the concurrency limit of the request and notification-only routes of this name (sharing the same limit).
+	///
+	/// Returns false if there is no route of this name.
+	#[cfg(feature = "tokio")]
+	pub fn set_route_concurrency_limit(&mut self, name: &str, concurrency_limit: ConcurrencyLimit) -> bool {
+		self.update_routes(name, |route| {
+			route.set_concurrency_limit(Some(concurrency_limit.clone()))
+		})
+	}
+
+	/// Add a guard after the existing ones (checked for all the routes).
+	pub fn append_guard(&mut self, guard: Arc<dyn Guard>) {
+		self.guards.push(guard);
+	}
+
+	/// Adds a guard after the existing route guards of the request and notification-only routes of this name.
+	///
+	/// Returns false if there is no route of this name.
+	pub fn append_route_guard(&mut self, name: &str, guard: Arc<dyn Guard>) -> bool {
+		self.update_routes(name, |route| route.append_guard(guard.clone()))
+	}
+
+	/// Adds a layer after the existing route layers of the request and notification-only routes of this name.
+	///
+	/// Returns false if there is no route of this name.
+	pub fn append_route_layer(&mut self, name: &str, layer: Arc<dyn Layer>) -> bool {
+		self.update_routes(name, |route| route.append_layer(layer.clone()))
+	}
+
+	/// Records the name given to a per-method setting without a matching route (see `unknown_methods()`).
+	pub fn record_unknown_method(&mut self, name: &str) {
+		self.unknown_methods.push(name.into());
+	}
+
+	/// Applies `update_fn` to the request and notification-only routes of this name.
+	///
+	/// Returns false if there is no route of this name.
+	fn update_routes(&mut self, name: &str, mut update_fn: impl FnMut(&mut Route)) -> bool {
+		let routes = [self.route_by_name.get_mut(name), self.notification_by_name.get_mut(name)];
+		let mut found = false;
+		for route in routes.into_iter().flatten() {
+			update_fn(route);
+			found = true;
+		}
+		found
+	}
+
+	pub fn set_notification_error_policy(&mut self, policy: NotificationErrorPolicy) {
+		self.notification_error_policy = policy;
+	}
+
+	/// Add a layer after the existing ones (i.e., inner to the already added layers).
+	pub fn append_layer(&mut self, layer: Arc<dyn Layer>) {
+		self.layers.push(layer);
+	}
+
+	/// The layers and guards of `other_router` become route layers and guards of its routes only
+	/// (and of its fallback, if taken), executed after the layers and guards of `self` (as for `nest`).
+	///
+	/// Note: The notification error policy of `self` is kept.
+	///       The fallback, static lookup, trace context location, and metrics of `self` are kept,
+	///       or the ones of `other_router` are taken if `self` has none.
+	///       The default timeout of `other_router` becomes the timeout of its routes without their own timeout.
 	pub fn extend(&mut self, other_router: RouterInner) {
-		self.route_by_name.extend(other_router.route_by_name);
+		let other_router = other_router.with_default_timeout_on_routes();
+		let RouterInner {
+			route_by_name,
+			notification_by_name,
+			layers,
+			guards,
+			duplicate_methods,
+			unknown_methods,
+			fallback,
+			builtin_methods,
+			static_lookup,
+			trace_context_location,
+			metrics,
+			..
+		} = other_router;
+
+		if self.fallback.is_none() {
+			self.fallback = fallback.map(|mut fallback| {
+				fallback.prepend_layers(&layers);
+				fallback.prepend_guards(&guards);
+				fallback
+			});
+		}
+		if self.static_lookup.is_none() {
+			self.static_lookup = static_lookup;
+		}
+		if self.trace_context_location.is_none() {
+			self.trace_context_location = trace_context_location;
+		}
+		if self.metrics.is_none() {
+			self.metrics = metrics;
+		}
+		self.builtin_methods.merge(builtin_methods);
+
+		self.duplicate_methods.extend(duplicate_methods);
+		self.unknown_methods.extend(unknown_methods);
+		for (kind, name, mut route) in into_ordered_routes(route_by_name, notification_by_name) {
+			route.prepend_layers(&layers);
+			route.prepend_guards(&guards);
+			self.append_route(kind, name, route);
+		}
+	}
+
+	/// Extends this router with the routes of `other_router` mounted under `prefix`
+	/// (i.e., `{prefix}{separator}{method_name}`).
+	///
+	/// The layers of `other_router` become route layers of its routes only (executed after the layers of `self`).
+	///
+	/// The default timeout of `other_router` becomes the timeout of its routes without their own timeout.
+	///
+	/// Note: The notification error policy, fallback, built-in methods, static lookup, trace context location,
+	///       and metrics of `other_router` are ignored.
+	pub fn nest(&mut self, prefix: &str, separator: &str, other_router: RouterInner) {
+		let other_router = other_router.with_default_timeout_on_routes();
+		let RouterInner {
+			route_by_name,
+			notification_by_name,
+			layers,
+			guards,
+			duplicate_methods,
+			unknown_methods,
+			..
+		} = other_router;
+
+		let prefixed = |name: Arc<str>| Arc::from(format!("{prefix}{separator}{name}"));
+		self.duplicate_methods.extend(duplicate_methods.into_iter().map(prefixed));
+		self.unknown_methods.extend(unknown_methods.into_iter().map(prefixed));
+		for (kind, name, mut route) in into_ordered_routes(route_by_name, notification_by_name) {
+			route.prepend_layers(&layers);
+			route.prepend_guards(&guards);
+			self.append_route(kind, format!("{prefix}{separator}{name}").into(), route);
+		}
+	}
+
+	/// Sets the default timeout as the timeout of the routes without their own timeout
+	/// (before merging them into another router).
+	#[cfg(feature = "tokio")]
+	fn with_default_timeout_on_routes(mut self) -> Self {
+		if let Some(timeout) = self.default_timeout {
+			for route in self.route_by_name.values_mut().chain(self.notification_by_name.values_mut()) {
+				if route.timeout().is_none() {
+					route.set_timeout(Some(timeout));
+				}
+			}
+		}
+		self
+	}
+
+	#[cfg(not(feature = "tokio"))]
+	fn with_default_timeout_on_routes(self) -> Self {
+		self
+	}
+
+	/// Inserts the route, and records the name as a duplicate method if it replaced an existing route
+	/// of the same kind (see `duplicate_methods()`).
+	fn append_route(&mut self, kind: RouteKind, name: Arc<str>, route: Route) {
+		if self.insert_route(kind, name.clone(), route) {
+			self.duplicate_methods.push(name);
+		}
+	}
+
+	/// Inserts the route with the next registration order.
+	///
+	/// Returns true if a route of the same kind and name was replaced.
+	fn insert_route(&mut self, kind: RouteKind, name: Arc<str>, mut route: Route) -> bool {
+		route.set_name(name.clone());
+		route.set_order(self.next_order);
+		self.next_order += 1;
+
+		let replaced = match kind {
+			RouteKind::Request => self.route_by_name.insert(name, route),
+			RouteKind::Notification => self.notification_by_name.insert(name, route),
+		};
+
+		replaced.is_some()
+	}
+
+	/// The method names registered more than once, in detection order.
+	pub fn duplicate_methods(&self) -> &[Arc<str>] {
+		&self.duplicate_methods
+	}
+
+	/// The method names given to a per-method setting without a matching route, in call order.
+	pub fn unknown_methods(&self) -> &[Arc<str>] {
+		&self.unknown_methods
+	}
+
+	/// Returns the information of all the routes, in registration order.
+	pub fn routes(&self) -> Vec<RouteInfo> {
+		let mut routes: Vec<RouteInfo> = self
+			.route_by_name
+			.iter()
+			.map(|(name, route)| route_info(RouteKind::Request, name, route))
+			.chain(
+				self.notification_by_name
+					.iter()
+					.map(|(name, route)| route_info(RouteKind::Notification, name, route)),
+			)
+			.collect();
+		routes.sort_by_key(|route| route.order);
+		routes
+	}
+
+	/// Returns the information of the route matching this name (request route first, then notification-only).
+	pub fn route_info(&self, name: &str) -> Option<RouteInfo> {
+		if let Some((name, route)) = self.route_by_name.get_key_value(name) {
+			Some(route_info(RouteKind::Request, name, route))
+		} else {
+			self.notification_by_name
+				.get_key_value(name)
+				.map(|(name, route)| route_info(RouteKind::Notification, name, route))
+		}
+	}
+
+	/// Returns the request method names (callable by requests), in registration order.
+	pub fn methods(&self) -> Vec<&str> {
+		let mut routes: Vec<(&Arc<str>, &Route)> = self.route_by_name.iter().collect();
+		routes.sort_by_key(|(_, route)| route.order());
+		routes.into_iter().map(|(name, _)| name.as_ref()).collect()
+	}
+
+	pub fn has_method(&self, name: &str) -> bool {
+		self.route_by_name.contains_key(name)
 	}
 
 	/// Performs the RPC call for a given Request object, which contains the `id`, method name, and parameters.
@@ -49,6 +481,117 @@ impl RouterInner {
 		self.call_route(resources, id, method, params).await
 	}
 
+	/// Performs all the calls of a JSON-RPC batch concurrently, and returns the responses
+	/// in the order of the batch requests.
+	///
+	/// - Requests get their `RpcResponse` (success or error) with their `id`.
+	/// - Notifications are executed, but do not get a response.
+	/// - Invalid items get a `-32600 Invalid Request` error response with a `null` id.
+	///
+	/// Returns None if there is nothing to respond (i.e., the batch had only notifications).
+	pub async fn call_batch(&self, resources: Resources, batch_request: RpcBatchRequest) -> Option<RpcBatchResponse> {
+		self.call_batch_with_trace_contexts(resources, batch_request, Vec::new()).await
+	}
+
+	/// Same as `call_batch`, with the trace context of each batch item (by index) overlaid in its resources.
+	async fn call_batch_with_trace_contexts(
+		&self,
+		resources: Resources,
+		batch_request: RpcBatchRequest,
+		trace_contexts: Vec<Option<TraceContext>>,
+	) -> Option<RpcBatchResponse> {
+		let mut trace_contexts = trace_contexts.into_iter();
+		let futs = batch_request.into_items().into_iter().map(|item| {
+			let resources = with_trace_context(&resources, trace_contexts.next().flatten());
+			async move {
+				match item {
+					RpcBatchItem::Request(rpc_request) => {
+						Some(RpcResponse::from(self.call(resources, rpc_request).await))
+					}
+					RpcBatchItem::Notification(notification) => {
+						self.notify(resources, notification).await;
+						None
+					}
+					RpcBatchItem::Invalid(err) => Some(RpcResponse::from_error(RpcId::Null, RpcError::from(err))),
+				}
+			}
+		});
+
+		let responses: Vec<RpcResponse> = join_all(futs).await.into_iter().flatten().collect();
+
+		if responses.is_empty() {
+			None
+		} else {
+			Some(RpcBatchResponse::new(responses))
+		}
+	}
+
+	/// Executes the handler matching the notification method. Notifications do not get a response,
+	/// so the eventual error is passed to the `NotificationErrorPolicy` of the router.
+	///
+	/// The notification-only handler is used if present, otherwise, the request handler of the same name.
+	pub async fn notify(&self, resources: Resources, notification: RpcNotification) {
+		let RpcNotification { method, params } = notification;
+		let route = self.find_route(&method, true);
+		let call = LayerCall::new_notification(self.method_name(&method, route), params, resources);
+
+		if let Err(call_error) = self.dispatch(call, route).await {
+			self.notification_error_policy.handle(&call_error);
+		}
+	}
+
+	/// Handles any raw incoming JSON-RPC message (request, notification, or batch)
+	/// and returns the JSON-RPC response value to send back, if any.
+	///
+	/// - Object with an `id` property: parsed as `RpcRequest`, and responded with its `RpcResponse`.
+	/// - Object without an `id` property: parsed as `RpcNotification`, and not responded (unless invalid).
+	/// - Array: parsed as `RpcBatchRequest`, and responded with the `RpcBatchResponse` (unless only notifications).
+	/// - Invalid message: responded with the `RpcError` of the `RpcRequestParsingError` and a `null` id.
+	///
+	/// If enabled (see `RouterBuilder::trace_context`), the `TraceContext` of each request and notification
+	/// is extracted from the message (and removed from the params for `ParamsMeta`, see `TraceContext::take`),
+	/// and overlaid in its call resources.
+	pub async fn handle_value(&self, resources: Resources, mut value: Value) -> Option<Value> {
+		match value {
+			Value::Array(ref mut values) => {
+				let trace_contexts = match self.trace_context_location {
+					Some(location) => values.iter_mut().map(|value| TraceContext::take(value, location)).collect(),
+					None => Vec::new(),
+				};
+				match RpcBatchRequest::from_value(value) {
+					Ok(batch_request) => self
+						.call_batch_with_trace_contexts(resources, batch_request, trace_contexts)
+						.await
+						.map(|res| res.to_value()),
+					Err(err) => Some(parsing_error_response_value(err)),
+				}
+			}
+			Value::Object(ref obj) if !obj.contains_key("id") => {
+				let resources = with_trace_context(&resources, self.take_trace_context(&mut value));
+				match RpcNotification::from_value(value) {
+					Ok(notification) => {
+						self.notify(resources, notification).await;
+						None
+					}
+					Err(err) => Some(parsing_error_response_value(err)),
+				}
+			}
+			_ => {
+				let resources = with_trace_context(&resources, self.take_trace_context(&mut value));
+				match RpcRequest::from_value(value) {
+					Ok(rpc_request) => Some(RpcResponse::from(self.call(resources, rpc_request).await).to_value()),
+					Err(err) => Some(parsing_error_response_value(err)),
+				}
+			}
+		}
+	}
+
+	/// Takes the trace context of the raw message, if enabled (see `RouterBuilder::trace_context`) and present.
+	fn take_trace_context(&self, value: &mut Value) -> Option<TraceContext> {
+		self.trace_context_location
+			.and_then(|location| TraceContext::take(value, location))
+	}
+
 	/// Performs the RPC call given the id, method, and params.
 	///
 	/// - method: The json-rpc method name.
@@ -61,26 +604,154 @@ impl RouterInner {
 		&self,
 		resources: Resources,
 		id: RpcId,
-		method: impl Into<String>,
+		method: impl AsRef<str>,
 		params: Option<Value>,
 	) -> CallResult {
-		let method = method.into();
-
-		if let Some(route) = self.route_by_name.get(method.as_str()) {
-			match route.call(resources, params).await {
-				Ok(value) => Ok(CallSuccess {
-					id: id.clone(), // Clone id for the response
-					method: method.clone(),
-					value,
-				}),
-				Err(error) => Err(CallError { id, method, error }),
-			}
+		let method = method.as_ref();
+		let route = self.find_route(method, false);
+		let call = LayerCall::new(id, self.method_name(method, route), params, resources);
+
+		self.dispatch(call, route).await
+	}
+
+	/// Runs the call in its `rpc.call` span, so the events of the layers and handler inherit the call context,
+	/// and records the outcome on the span.
+	#[cfg(feature = "tracing")]
+	async fn dispatch(&self, call: LayerCall, route: Option<&Route>) -> CallResult {
+		use tracing::Instrument;
+
+		let span = call_span(&call);
+		let call_result = self.dispatch_recorded(call, route).instrument(span.clone()).await;
+		record_outcome(&span, &call_result);
+		call_result
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	async fn dispatch(&self, call: LayerCall, route: Option<&Route>) -> CallResult {
+		self.dispatch_recorded(call, route).await
+	}
+
+	/// Runs the call through the layers and the route handler (`route` being the route of the call method,
+	/// reused by the layer pipeline), and records its metrics (if enabled).
+	///
+	/// Note: The calls without a matching route are recorded under `METRICS_UNMATCHED_METHOD`.
+	async fn dispatch_recorded(&self, call: LayerCall, route: Option<&Route>) -> CallResult {
+		let dispatched = DispatchedRoute {
+			method: call.method.clone(),
+			route,
+		};
+		let next = Next::new(self, &self.layers, &dispatched);
+
+		let recorder = match route {
+			Some(route) => route.recorder(),
+			None => self.metrics.as_ref().map(|metrics| metrics.unmatched_recorder()),
+		};
+
+		match recorder {
+			Some(recorder) => recorder.record(next.run(call)).await,
+			None => next.run(call).await,
+		}
+	}
+
+	/// Returns the route matching the call method.
+	///
+	/// For notifications, the notification-only route takes precedence over the request route.
+	pub(crate) fn get_route(&self, call: &LayerCall) -> Option<&Route> {
+		self.find_route(&call.method, call.notification)
+	}
+
+	/// Returns the shared name of the method, from the route table if it has a route,
+	/// or interned otherwise (e.g., for the fallback), so the calls do not allocate their method name.
+	fn method_name(&self, method: &str, route: Option<&Route>) -> Arc<str> {
+		match route {
+			Some(route) => route.name().clone(),
+			None => self.unknown_method_names.intern(method),
+		}
+	}
+
+	fn find_route(&self, method: &str, notification: bool) -> Option<&Route> {
+		if notification {
+			self.notification_by_name.get(method).or_else(|| self.get_request_route(method))
 		} else {
-			Err(CallError {
-				id,
-				method,
-				error: Error::MethodUnknown,
-			})
+			self.get_request_route(method)
+		}
+	}
+
+	/// Returns the request route, from the static table first (if any), then from the `HashMap`.
+	fn get_request_route(&self, method: &str) -> Option<&Route> {
+		if let Some(static_lookup) = &self.static_lookup {
+			let static_route = static_lookup
+				.lookup(method)
+				.and_then(|idx| self.static_routes.get(idx)?.as_ref());
+			if static_route.is_some() || self.static_complete {
+				return static_route;
+			}
 		}
+
+		self.route_by_name.get(method)
+	}
+
+	/// Checks the router guards, then the route guards, and returns the first rejection.
+	pub(crate) fn check_guards(&self, route: &Route, call: &LayerCall) -> Result<(), GuardRejection> {
+		let guard_call = GuardCall {
+			method: &call.method,
+			meta: route.meta(),
+			resources: &call.resources,
+		};
+
+		self.guards
+			.iter()
+			.chain(route.guards())
+			.try_for_each(|guard| guard.check(&guard_call))
+	}
+
+	/// Returns the fallback route, called when `get_route` has no match.
+	pub(crate) fn fallback(&self) -> Option<&Route> {
+		self.fallback.as_ref()
 	}
 }
+
+// region:    --- Support
+
+/// Returns the resources with the trace context overlaid (if any).
+fn with_trace_context(resources: &Resources, trace_context: Option<TraceContext>) -> Resources {
+	match trace_context {
+		Some(trace_context) => resources.new_with_overlay_resource(trace_context),
+		None => resources.clone(),
+	}
+}
+
+fn route_info(kind: RouteKind, name: &Arc<str>, route: &Route) -> RouteInfo {
+	RouteInfo {
+		name: name.clone(),
+		order: route.order(),
+		kind,
+		meta: route.meta().clone(),
+	}
+}
+
+/// Returns all the routes of both maps, in their registration order.
+fn into_ordered_routes(
+	route_by_name: HashMap<Arc<str>, Route>,
+	notification_by_name: HashMap<Arc<str>, Route>,
+) -> Vec<(RouteKind, Arc<str>, Route)> {
+	let mut routes: Vec<(RouteKind, Arc<str>, Route)> = route_by_name
+		.into_iter()
+		.map(|(name, route)| (RouteKind::Request, name, route))
+		.chain(
+			notification_by_name
+				.into_iter()
+				.map(|(name, route)| (RouteKind::Notification, name, route)),
+		)
+		.collect();
+	routes.sort_by_key(|(_, _, route)| route.order());
+	routes
+}
+
+/// Builds the error response value for a message that could not be parsed.
+/// Per the spec, the `id` is `null` since it could not be reliably determined.
+pub(crate) fn parsing_error_response_value(err: RpcRequestParsingError) -> Value {
+	RpcResponse::from_error(RpcId::Null, RpcError::from(err)).to_value()
+}
+
+// endregion: --- Support
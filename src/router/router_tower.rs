@@ -0,0 +1,27 @@
+//! `tower::Service` implementation for `Router` (behind the `tower` feature).
+//!
+//! This allows to use the tower ecosystem layers (timeout, concurrency-limit, load-shed, tracing, ...)
+//! around the router.
+
+use crate::{CallError, CallResult, CallSuccess, Router, RpcRequest};
+use futures::future::BoxFuture;
+use std::task::{Context, Poll};
+
+impl tower_service::Service<RpcRequest> for Router {
+	type Response = CallSuccess;
+	type Error = CallError;
+	type Future = BoxFuture<'static, CallResult>;
+
+	/// The router is always ready, as it does not hold any per-call capacity.
+	fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		Poll::Ready(Ok(()))
+	}
+
+	/// Performs the `Router::call(rpc_request)` with the router base resources.
+	///
+	/// Note: The router is cloned for the returned future (inner `Arc` clones).
+	fn call(&mut self, rpc_request: RpcRequest) -> Self::Future {
+		let router = self.clone();
+		Box::pin(async move { router.call(rpc_request).await })
+	}
+}
@@ -0,0 +1,305 @@
+/// The compile-time method lookup of a static router (see `static_router_builder!`),
+/// which gives the index of a method name in `names` with the perfect hash table of a `StaticTable`.
+///
+/// When the router is built, the routes of `names` are put in a table by index,
+/// so the dispatch of those methods does not go through the `HashMap` route table.
+///
+/// Note: The methods not in `names` (e.g., appended after, or built-in methods) are still dispatched
+///       with the `HashMap` route table.
+#[derive(Debug, Clone, Copy)]
+pub struct StaticLookup {
+	/// The method names, by lookup index.
+	pub names: &'static [&'static str],
+	seed: u64,
+	displacements: &'static [(u32, u32)],
+	slots: &'static [usize],
+}
+
+impl StaticLookup {
+	/// Returns the index of the method name in `names`, or None if not a static method.
+	///
+	/// Note: The method name is hashed once, and compared once with the name of its slot.
+	pub fn lookup(&self, method: &str) -> Option<usize> {
+		let len = self.slots.len() as u32;
+		if len == 0 {
+			return None;
+		}
+
+		let (g, f1, f2) = hash(self.seed, method);
+		let (d1, d2) = self.displacements[(g % len) as usize];
+		let idx = self.slots[(displace(f1, f2, d1, d2) % len) as usize];
+
+		(self.names[idx] == method).then_some(idx)
+	}
+}
+
+// region:    --- StaticTable
+
+/// The perfect hash table of `N` method names, computed at compile time (typically in a `static`
+/// generated by `static_router_builder!`), with the hash and displace algorithm (as `phf`).
+///
+/// The names are hashed into `N` buckets, and each bucket gets the displacements that put its names
+/// in free slots (the largest buckets first), so each name has its own slot in the `N` slots.
+///
+/// Note: The duplicate names fail the compile-time evaluation (no table could separate them).
+pub struct StaticTable<const N: usize> {
+	names: [&'static str; N],
+	seed: u64,
+	displacements: [(u32, u32); N],
+	slots: [usize; N],
+}
+
+impl<const N: usize> StaticTable<N> {
+	/// Computes the perfect hash table of the names (intended for a `const` or `static` initializer).
+	pub const fn new(names: [&'static str; N]) -> Self {
+		assert!(N <= u32::MAX as usize, "too many static method names");
+
+		let mut i = 0;
+		while i < N {
+			let mut j = i + 1;
+			while j < N {
+				assert!(!str_eq(names[i], names[j]), "duplicate static method name");
+				j += 1;
+			}
+			i += 1;
+		}
+
+		// Note: A displacement search rarely fails, in which case the names are hashed again with the next seed.
+		let mut seed = 0;
+		loop {
+			if let Some((displacements, slots)) = try_displace(&names, seed) {
+				return Self {
+					names,
+					seed,
+					displacements,
+					slots,
+				};
+			}
+			seed += 1;
+		}
+	}
+
+	/// Returns the lookup of this table.
+	pub const fn lookup(&'static self) -> StaticLookup {
+		StaticLookup {
+			names: &self.names,
+			seed: self.seed,
+			displacements: &self.displacements,
+			slots: &self.slots,
+		}
+	}
+}
+
+/// The marker of a free slot during the displacement search.
+const FREE_SLOT: usize = usize::MAX;
+
+/// Returns the displacements of each bucket, and the name index of each slot,
+/// or None if a bucket has no displacements putting its names in free slots.
+#[allow(clippy::type_complexity)]
+const fn try_displace<const N: usize>(names: &[&'static str; N], seed: u64) -> Option<([(u32, u32); N], [usize; N])> {
+	let len = N as u32;
+	let mut hashes = [(0, 0, 0); N];
+	let mut bucket_sizes = [0; N];
+	let mut max_bucket_size = 0;
+	let mut i = 0;
+	while i < N {
+		hashes[i] = hash(seed, names[i]);
+		let bucket = (hashes[i].0 % len) as usize;
+		bucket_sizes[bucket] += 1;
+		if bucket_sizes[bucket] > max_bucket_size {
+			max_bucket_size = bucket_sizes[bucket];
+		}
+		i += 1;
+	}
+
+	let mut displacements = [(0, 0); N];
+	let mut slots = [FREE_SLOT; N];
+
+	// Note: The largest buckets are placed first, while most slots are free.
+	let mut bucket_size = max_bucket_size;
+	while bucket_size > 0 {
+		let mut bucket = 0;
+		while bucket < N {
+			if bucket_sizes[bucket] == bucket_size {
+				match place_bucket(&hashes, bucket, &mut slots) {
+					Some(bucket_displacements) => displacements[bucket] = bucket_displacements,
+					None => return None,
+				}
+			}
+			bucket += 1;
+		}
+		bucket_size -= 1;
+	}
+
+	Some((displacements, slots))
+}
+
+/// Finds the first displacements putting all the names of the bucket in free (and distinct) slots,
+/// and marks those slots with the name indexes.
+const fn place_bucket<const N: usize>(
+	hashes: &[(u32, u32, u32); N],
+	bucket: usize,
+	slots: &mut [usize; N],
+) -> Option<(u32, u32)> {
+	let len = N as u32;
+	let mut d1 = 0;
+	while d1 < len {
+		let mut d2 = 0;
+		while d2 < len {
+			let mut placed = true;
+			let mut i = 0;
+			while i < N {
+				let (g, f1, f2) = hashes[i];
+				if (g % len) as usize == bucket {
+					let slot = (displace(f1, f2, d1, d2) % len) as usize;
+					if slots[slot] != FREE_SLOT {
+						placed = false;
+						break;
+					}
+					slots[slot] = i;
+				}
+				i += 1;
+			}
+			if placed {
+				return Some((d1, d2));
+			}
+
+			// -- Free the slots taken by this attempt (the names of the bucket before `i`).
+			let mut j = 0;
+			while j < i {
+				let (g, f1, f2) = hashes[j];
+				if (g % len) as usize == bucket {
+					slots[(displace(f1, f2, d1, d2) % len) as usize] = FREE_SLOT;
+				}
+				j += 1;
+			}
+			d2 += 1;
+		}
+		d1 += 1;
+	}
+
+	None
+}
+
+// endregion: --- StaticTable
+
+// region:    --- Support
+
+/// Returns the bucket hash and the two slot hashes of the name (FNV-1a, then mixed),
+/// the same function being evaluated at compile time for the table and at runtime for the lookup.
+const fn hash(seed: u64, name: &str) -> (u32, u32, u32) {
+	let bytes = name.as_bytes();
+	let mut h: u64 = 0xcbf2_9ce4_8422_2325 ^ seed.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+	let mut i = 0;
+	while i < bytes.len() {
+		h ^= bytes[i] as u64;
+		h = h.wrapping_mul(0x0000_0100_0000_01b3);
+		i += 1;
+	}
+
+	let h1 = mix(h);
+	let h2 = mix(h1 ^ 0x9e37_79b9_7f4a_7c15);
+	((h1 >> 32) as u32, h1 as u32, h2 as u32)
+}
+
+/// The `splitmix64` finalizer, so all the bits of the hash depend on all the bytes of the name.
+const fn mix(mut h: u64) -> u64 {
+	h ^= h >> 30;
+	h = h.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+	h ^= h >> 27;
+	h = h.wrapping_mul(0x94d0_49bb_1331_11eb);
+	h ^ (h >> 31)
+}
+
+const fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+	d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+	let (a, b) = (a.as_bytes(), b.as_bytes());
+	if a.len() != b.len() {
+		return false;
+	}
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+		i += 1;
+	}
+	true
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const NAMES: [&str; 40] = [
+		"get_project",
+		"list_projects",
+		"create_project",
+		"update_project",
+		"delete_project",
+		"get_task",
+		"list_tasks",
+		"create_task",
+		"update_task",
+		"delete_task",
+		"get_user",
+		"list_users",
+		"create_user",
+		"update_user",
+		"delete_user",
+		"get_team",
+		"list_teams",
+		"create_team",
+		"update_team",
+		"delete_team",
+		"get_comment",
+		"list_comments",
+		"create_comment",
+		"update_comment",
+		"delete_comment",
+		"get_label",
+		"list_labels",
+		"create_label",
+		"update_label",
+		"delete_label",
+		"a",
+		"b",
+		"ab",
+		"ba",
+		"",
+		"rpc",
+		"rpc.",
+		"get_task_",
+		"_get_task",
+		"GET_TASK",
+	];
+
+	static TABLE: StaticTable<40> = StaticTable::new(NAMES);
+	static EMPTY_TABLE: StaticTable<0> = StaticTable::new([]);
+
+	#[test]
+	fn test_static_table_lookup() {
+		// -- Setup & Fixtures
+		let static_lookup = TABLE.lookup();
+
+		// -- Exec
+		let indexes: Vec<Option<usize>> = NAMES.iter().map(|name| static_lookup.lookup(name)).collect();
+
+		// -- Check
+		let expected: Vec<Option<usize>> = (0..NAMES.len()).map(Some).collect();
+		assert_eq!(indexes, expected);
+		for unknown in ["get_tasks", "Get_task", "rpc.ping", "delete_label ", "c"] {
+			assert_eq!(static_lookup.lookup(unknown), None, "{unknown} should not be found");
+		}
+		assert_eq!(EMPTY_TABLE.lookup().lookup("get_task"), None);
+	}
+}
+
+// endregion: --- Tests
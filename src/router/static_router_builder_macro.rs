@@ -0,0 +1,51 @@
+/// Same as `router_builder!`, but also generates the compile-time method lookup (a perfect hash table
+/// of the method names, see `StaticTable`) of the handlers, so the `Router` dispatches them by index
+/// rather than by `HashMap` lookup.
+///
+/// It supports the same patterns as `router_builder!`:
+///
+/// ```
+/// static_router_builder!(create_project, list_projects);
+///
+/// static_router_builder!(
+///   handlers: [get_task, create_task],
+///   resources: [ModelManager {}, AiManager {}]
+/// );
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```
+/// static TABLE: StaticTable<2> = StaticTable::new(["get_task", "create_task"]);
+///
+/// router_builder!(handlers: [get_task, create_task], resources: [ModelManager {}, AiManager {}])
+///     .static_lookup(TABLE.lookup())
+/// ```
+///
+/// Note: The routes appended after (e.g., with `.append(..)` on the returned builder) are still dispatched,
+///       with the `HashMap` route table.
+///       The table is computed in a `static`, so a duplicate handler name is a compile-time error.
+#[macro_export]
+macro_rules! static_router_builder {
+	// Pattern 1 - with `static_router_builder!(my_fn1, myfn2)`
+	($($fn_name:ident),+ $(,)?) => {
+		$crate::static_router_builder!(handlers: [$($fn_name),+])
+	};
+
+	// Pattern 2 - `static_router_builder!(handlers: [my_fn1, myfn2], resources: [ModelManger {}, AiManager {}])`
+	(handlers: [$($handler:ident),* $(,)?], resources: [$($resource:expr),* $(,)?]) => {{
+		let mut builder = $crate::static_router_builder!(handlers: [$($handler),*]);
+		$(
+			builder = builder.append_resource($resource);
+		)*
+		builder
+	}};
+
+	// Pattern 3 - with `static_router_builder!(handlers: [my_fn1, myfn2])`
+	(handlers: [$($handler:ident),* $(,)?]) => {{
+		const NAMES: &[&str] = &[$(stringify!($handler)),*];
+		static TABLE: $crate::StaticTable<{ NAMES.len() }> = $crate::StaticTable::new([$(stringify!($handler)),*]);
+
+		$crate::router_builder!(handlers: [$($handler),*]).static_lookup(TABLE.lookup())
+	}};
+}
@@ -0,0 +1,255 @@
+//! Represents a JSON-RPC Batch request (array of requests and/or notifications).
+
+use crate::support::get_json_type;
+use crate::{RpcNotification, RpcRequest, RpcRequestParsingError};
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// Represents a JSON-RPC 2.0 Batch, which is a non-empty JSON array of request and/or notification objects.
+/// <https://www.jsonrpc.org/specification#batch>
+///
+/// Note: Per the spec, an invalid element does not invalidate the whole batch. It is kept as
+///       `RpcBatchItem::Invalid` so that the router can answer it with its own `-32600` error response.
+#[derive(Debug)]
+pub struct RpcBatchRequest {
+	items: Vec<RpcBatchItem>,
+}
+
+/// One element of a `RpcBatchRequest`.
+#[derive(Debug)]
+pub enum RpcBatchItem {
+	/// A request with an `id`, which expects a response.
+	Request(RpcRequest),
+	/// A notification (no `id`), which does not get a response.
+	Notification(RpcNotification),
+	/// An element that failed the JSON-RPC validation. It gets a `-32600` error response with a `null` id.
+	Invalid(RpcRequestParsingError),
+}
+
+// region:    --- Constructors
+
+impl RpcBatchRequest {
+	/// Creates a new batch from its items.
+	///
+	/// Returns `RpcRequestParsingError::BatchEmpty` if `items` is empty, as an empty batch is invalid per the spec.
+	pub fn new(items: Vec<RpcBatchItem>) -> Result<Self, RpcRequestParsingError> {
+		if items.is_empty() {
+			return Err(RpcRequestParsingError::BatchEmpty);
+		}
+		Ok(Self { items })
+	}
+
+	/// Parses a `serde_json::Value` into an `RpcBatchRequest`.
+	///
+	/// - The value must be a non-empty JSON array.
+	/// - Each element with an `id` property is parsed with `RpcRequest::from_value`.
+	/// - Each element without an `id` property is parsed with `RpcNotification::from_value`.
+	/// - Elements failing validation are kept as `RpcBatchItem::Invalid`.
+	///
+	/// # Errors
+	/// Returns `RpcRequestParsingError::BatchInvalidType` if the value is not an array,
+	/// and `RpcRequestParsingError::BatchEmpty` if the array is empty.
+	pub fn from_value(value: Value) -> Result<RpcBatchRequest, RpcRequestParsingError> {
+		let Value::Array(values) = value else {
+			return Err(RpcRequestParsingError::BatchInvalidType {
+				actual_type: get_json_type(&value).to_string(),
+			});
+		};
+
+		let items = values.into_iter().map(RpcBatchItem::from_value).collect();
+
+		RpcBatchRequest::new(items)
+	}
+}
+
+impl RpcBatchItem {
+	/// Parses one batch element. This never fails, as invalid elements become `RpcBatchItem::Invalid`.
+	pub fn from_value(value: Value) -> RpcBatchItem {
+		let is_request = value.as_object().is_some_and(|obj| obj.contains_key("id"));
+
+		if is_request {
+			match RpcRequest::from_value(value) {
+				Ok(request) => RpcBatchItem::Request(request),
+				Err(err) => RpcBatchItem::Invalid(err),
+			}
+		} else {
+			match RpcNotification::from_value(value) {
+				Ok(notification) => RpcBatchItem::Notification(notification),
+				Err(err) => RpcBatchItem::Invalid(err),
+			}
+		}
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Accessors
+
+impl RpcBatchRequest {
+	pub fn items(&self) -> &[RpcBatchItem] {
+		&self.items
+	}
+
+	pub fn into_items(self) -> Vec<RpcBatchItem> {
+		self.items
+	}
+
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	/// Always false for a constructed batch (kept for API completeness with `len`).
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+}
+
+// endregion: --- Accessors
+
+// region:    --- Serialize Custom
+
+/// Serializes the valid items only (invalid items cannot be represented back as JSON-RPC messages).
+impl Serialize for RpcBatchRequest {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut seq = serializer.serialize_seq(None)?;
+
+		for item in self.items.iter() {
+			match item {
+				RpcBatchItem::Request(request) => seq.serialize_element(request)?,
+				RpcBatchItem::Notification(notification) => seq.serialize_element(notification)?,
+				RpcBatchItem::Invalid(_) => (),
+			}
+		}
+
+		seq.end()
+	}
+}
+
+// endregion: --- Serialize Custom
+
+// region:    --- Froms
+
+impl From<RpcRequest> for RpcBatchItem {
+	fn from(request: RpcRequest) -> Self {
+		RpcBatchItem::Request(request)
+	}
+}
+
+impl From<RpcNotification> for RpcBatchItem {
+	fn from(notification: RpcNotification) -> Self {
+		RpcBatchItem::Notification(notification)
+	}
+}
+
+// endregion: --- Froms
+
+// region:    --- TryFrom
+
+/// Convenient TryFrom, performs the JSON-RPC 2.0 batch validation via `RpcBatchRequest::from_value`.
+impl TryFrom<Value> for RpcBatchRequest {
+	type Error = RpcRequestParsingError;
+	fn try_from(value: Value) -> Result<RpcBatchRequest, RpcRequestParsingError> {
+		RpcBatchRequest::from_value(value)
+	}
+}
+
+// endregion: --- TryFrom
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::{json, to_value};
+
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	#[test]
+	fn test_rpc_batch_from_value_ok_mixed() -> Result<()> {
+		// -- Setup & Fixtures
+		let value = json!([
+			{"jsonrpc": "2.0", "id": 1, "method": "sum", "params": [1, 2]},
+			{"jsonrpc": "2.0", "method": "notify_hello", "params": [7]},
+			{"foo": "boo"},
+			1
+		]);
+
+		// -- Exec
+		let batch = RpcBatchRequest::from_value(value)?;
+
+		// -- Check
+		let items = batch.items();
+		assert_eq!(items.len(), 4);
+		assert!(matches!(&items[0], RpcBatchItem::Request(req) if req.method == "sum"));
+		assert!(matches!(&items[1], RpcBatchItem::Notification(n) if n.method == "notify_hello"));
+		assert!(matches!(
+			&items[2],
+			RpcBatchItem::Invalid(RpcRequestParsingError::VersionMissing { .. })
+		));
+		assert!(matches!(
+			&items[3],
+			RpcBatchItem::Invalid(RpcRequestParsingError::RequestInvalidType { .. })
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rpc_batch_from_value_fail_empty() -> Result<()> {
+		// -- Exec
+		let result = RpcBatchRequest::from_value(json!([]));
+
+		// -- Check
+		assert!(matches!(result, Err(RpcRequestParsingError::BatchEmpty)));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rpc_batch_from_value_fail_not_array() -> Result<()> {
+		// -- Exec
+		let result = RpcBatchRequest::from_value(json!({"jsonrpc": "2.0", "id": 1, "method": "sum"}));
+
+		// -- Check
+		if let Err(RpcRequestParsingError::BatchInvalidType { actual_type }) = result {
+			assert_eq!(actual_type, "Object");
+		} else {
+			panic!("Expected BatchInvalidType error");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rpc_batch_serialize_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let batch = RpcBatchRequest::new(vec![
+			RpcRequest::new(1, "sum", Some(json!([1, 2]))).into(),
+			RpcNotification {
+				method: "ping".to_string(),
+				params: None,
+			}
+			.into(),
+		])?;
+
+		// -- Exec
+		let value = to_value(&batch)?;
+
+		// -- Check
+		assert_eq!(
+			value,
+			json!([
+				{"jsonrpc": "2.0", "id": 1, "method": "sum", "params": [1, 2]},
+				{"jsonrpc": "2.0", "method": "ping"}
+			])
+		);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,12 +1,16 @@
 // region:    --- Modules
 
+mod batch_request;
 mod notification;
 mod request;
 mod rpc_request_parsing_error;
 mod support;
+mod trace_context;
 
+pub use batch_request::*;
 pub use notification::*;
 pub use request::*;
 pub use rpc_request_parsing_error::*;
+pub use trace_context::*;
 
 // endregion: --- Modules
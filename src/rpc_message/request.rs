@@ -1,8 +1,8 @@
 use crate::support::get_json_type;
-use crate::{RpcId, RpcRequestParsingError};
+use crate::{RpcId, RpcRequestParsingError, TraceContext, TraceContextLocation};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serializer};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 /// The raw JSON-RPC request object, serving as the foundation for RPC routing.
 #[derive(Deserialize, Clone, Debug)]
@@ -114,6 +114,41 @@ impl RpcRequest {
 
 		Ok(RpcRequest { id, method, params })
 	}
+
+	/// Same as `from_value`, but also extracts the W3C trace context at this location (if present and valid).
+	///
+	/// Note: For `TraceContextLocation::ParamsMeta`, the trace context members are removed from the params
+	///       (see `TraceContext::take`).
+	pub fn from_value_with_trace_context(
+		mut value: Value,
+		location: TraceContextLocation,
+	) -> Result<(RpcRequest, Option<TraceContext>), RpcRequestParsingError> {
+		let trace_context = TraceContext::take(&mut value, location);
+		let rpc_request = RpcRequest::from_value(value)?;
+		Ok((rpc_request, trace_context))
+	}
+}
+
+// -- Client helpers
+impl RpcRequest {
+	/// Returns the JSON-RPC request object value, with the W3C trace context injected at this location
+	/// (e.g., to propagate the trace of the current call to an outgoing request).
+	///
+	/// Note: For `TraceContextLocation::ParamsMeta`, the trace context is not injected if the params are absent,
+	///       null, or positional.
+	pub fn to_value_with_trace_context(&self, trace_context: &TraceContext, location: TraceContextLocation) -> Value {
+		let mut obj = Map::new();
+		obj.insert("jsonrpc".to_string(), Value::from("2.0"));
+		obj.insert("id".to_string(), self.id.to_value());
+		obj.insert("method".to_string(), Value::from(self.method.as_str()));
+		if let Some(params) = &self.params {
+			obj.insert("params".to_string(), params.clone());
+		}
+
+		let mut value = Value::Object(obj);
+		trace_context.inject(&mut value, location);
+		value
+	}
 }
 
 // region:    --- Serialize Custom
@@ -13,8 +13,11 @@ use serde_with::{DisplayFromStr, serde_as};
 ///       or `"[object/array redacted, 'method' must be of type string]"`
 ///       This approach aims to provide sufficient context for debugging the issue while preventing
 ///       the capture of indefinitely large values in the logs.
+///
+/// Note: The enum is `#[non_exhaustive]`, as new message kinds (e.g., batches) add new variants.
 #[serde_as]
 #[derive(Debug, Serialize)]
+#[non_exhaustive]
 pub enum RpcRequestParsingError {
 	RequestInvalidType {
 		actual_type: String,
@@ -59,6 +62,11 @@ pub enum RpcRequestParsingError {
 		cause: String,
 	},
 
+	BatchInvalidType {
+		actual_type: String,
+	},
+	BatchEmpty,
+
 	Parse(#[serde_as(as = "DisplayFromStr")] serde_json::Error), // Generic serde error if basic JSON is invalid
 }
 
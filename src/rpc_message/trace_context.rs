@@ -0,0 +1,294 @@
+//! W3C Trace Context (<https://www.w3.org/TR/trace-context/>) propagation through JSON-RPC messages.
+
+use crate::FromResources;
+use serde_json::{Map, Value};
+
+/// The name of the `traceparent` member (in the request object or in the `params._meta` object).
+pub const TRACEPARENT: &str = "traceparent";
+
+/// The name of the `tracestate` member (in the request object or in the `params._meta` object).
+pub const TRACESTATE: &str = "tracestate";
+
+/// The name of the params member holding the trace context for `TraceContextLocation::ParamsMeta`.
+pub const PARAMS_META: &str = "_meta";
+
+/// Where the trace context is carried in the JSON-RPC request and notification objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TraceContextLocation {
+	/// Top-level `traceparent` and `tracestate` members of the request object (extension members).
+	#[default]
+	Extension,
+	/// `traceparent` and `tracestate` members of the `_meta` object of the by-name params
+	/// (e.g., `{"params": {"_meta": {"traceparent": "00-..."}, "id": 123}}`).
+	ParamsMeta,
+}
+
+/// The W3C trace context of a call, with a validated `traceparent` and the optional `tracestate`.
+///
+/// When enabled with `RouterBuilder::trace_context`, the router extracts it from the incoming messages,
+/// and overlays it in the call `Resources`, so handlers can take it as `TraceContext` or `Option<TraceContext>`.
+///
+/// ```
+/// pub async fn get_task(trace_context: Option<TraceContext>, params: ParamsIded) -> HandlerResult<Task> {
+///     // e.g., propagate to the outgoing requests
+///     let rpc_request = RpcRequest::new(1, "get_details", Some(json!({"id": params.id})));
+///     let value = match trace_context {
+///         Some(trace_context) => rpc_request.to_value_with_trace_context(&trace_context, TraceContextLocation::Extension),
+///         None => serde_json::to_value(rpc_request)?,
+///     };
+///     ...
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+	/// The validated `traceparent`, e.g., `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+	traceparent: String,
+	tracestate: Option<String>,
+}
+
+impl FromResources for TraceContext {}
+
+// -- Constructors
+impl TraceContext {
+	/// Parses and validates a `traceparent` header value (`version-trace_id-parent_id-flags`).
+	///
+	/// Returns None if the value is not a valid `traceparent` (e.g., invalid or uppercase hex, all-zero ids,
+	/// or `ff` version).
+	pub fn from_traceparent(traceparent: &str) -> Option<Self> {
+		let traceparent = traceparent.trim();
+
+		// Note: Future versions can append fields after the version `00` ones (prefixed by `-`).
+		let valid_len = match traceparent.get(..2) {
+			Some("00") => traceparent.len() == 55,
+			Some("ff") | None => false,
+			Some(_) => traceparent.len() == 55 || traceparent.as_bytes().get(55) == Some(&b'-'),
+		};
+		if !valid_len {
+			return None;
+		}
+
+		let mut parts = traceparent[..55].split('-');
+		let (Some(version), Some(trace_id), Some(parent_id), Some(flags), None) =
+			(parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+		else {
+			return None;
+		};
+
+		// Note: The spec only allows lowercase hex.
+		let is_hex = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+		let is_zero = |s: &str| s.bytes().all(|b| b == b'0');
+		if !is_hex(version, 2) || !is_hex(flags, 2) || !is_hex(trace_id, 32) || !is_hex(parent_id, 16) {
+			return None;
+		}
+		if is_zero(trace_id) || is_zero(parent_id) {
+			return None;
+		}
+
+		Some(Self {
+			traceparent: traceparent.to_string(),
+			tracestate: None,
+		})
+	}
+
+	/// Returns this trace context with the `tracestate` (e.g., `congo=t61rcWkgMzE`).
+	pub fn with_tracestate(mut self, tracestate: impl Into<String>) -> Self {
+		self.tracestate = Some(tracestate.into());
+		self
+	}
+}
+
+// -- Getters
+impl TraceContext {
+	pub fn traceparent(&self) -> &str {
+		&self.traceparent
+	}
+
+	pub fn tracestate(&self) -> Option<&str> {
+		self.tracestate.as_deref()
+	}
+
+	/// The 32 hex characters trace id.
+	pub fn trace_id(&self) -> &str {
+		&self.traceparent[3..35]
+	}
+
+	/// The 16 hex characters id of the caller span.
+	pub fn parent_id(&self) -> &str {
+		&self.traceparent[36..52]
+	}
+
+	pub fn flags(&self) -> u8 {
+		u8::from_str_radix(&self.traceparent[53..55], 16).unwrap_or_default()
+	}
+
+	/// Returns true if the caller may have recorded the trace (`sampled` flag).
+	pub fn is_sampled(&self) -> bool {
+		self.flags() & 0x01 == 0x01
+	}
+}
+
+// -- Extract & Inject
+impl TraceContext {
+	/// Extracts the trace context of a JSON-RPC request or notification object, at this location.
+	///
+	/// Returns None if there is no valid `traceparent` (an invalid `traceparent` is ignored, per the spec).
+	pub fn extract(value: &Value, location: TraceContextLocation) -> Option<Self> {
+		let obj = match location {
+			TraceContextLocation::Extension => value.as_object()?,
+			TraceContextLocation::ParamsMeta => value.get("params")?.get(PARAMS_META)?.as_object()?,
+		};
+
+		let trace_context = Self::from_traceparent(obj.get(TRACEPARENT)?.as_str()?)?;
+		match obj.get(TRACESTATE).and_then(Value::as_str) {
+			Some(tracestate) => Some(trace_context.with_tracestate(tracestate)),
+			None => Some(trace_context),
+		}
+	}
+
+	/// Same as `extract`, but also removes the trace context members from the params `_meta` object
+	/// (and the `_meta` itself if left empty) for `ParamsMeta`, so the params types do not get them
+	/// (e.g., with `#[serde(deny_unknown_fields)]`).
+	pub fn take(value: &mut Value, location: TraceContextLocation) -> Option<Self> {
+		let trace_context = Self::extract(value, location);
+
+		if location == TraceContextLocation::ParamsMeta
+			&& let Some(params) = value.get_mut("params").and_then(Value::as_object_mut)
+			&& let Some(meta) = params.get_mut(PARAMS_META).and_then(Value::as_object_mut)
+		{
+			meta.remove(TRACEPARENT);
+			meta.remove(TRACESTATE);
+			if meta.is_empty() {
+				params.remove(PARAMS_META);
+			}
+		}
+
+		trace_context
+	}
+
+	/// Injects the trace context into a JSON-RPC request or notification object, at this location.
+	///
+	/// Returns false if the location is not available (i.e., not an object, or for `ParamsMeta`,
+	/// absent, null, or positional params, so the params of a method without params are not changed).
+	pub fn inject(&self, value: &mut Value, location: TraceContextLocation) -> bool {
+		let Some(obj) = value.as_object_mut() else {
+			return false;
+		};
+
+		let obj = match location {
+			TraceContextLocation::Extension => obj,
+			TraceContextLocation::ParamsMeta => {
+				let Some(params) = obj.get_mut("params").and_then(Value::as_object_mut) else {
+					return false;
+				};
+				let Some(meta) = params
+					.entry(PARAMS_META)
+					.or_insert_with(|| Value::Object(Map::new()))
+					.as_object_mut()
+				else {
+					return false;
+				};
+				meta
+			}
+		};
+
+		obj.insert(TRACEPARENT.to_string(), Value::String(self.traceparent.clone()));
+		if let Some(tracestate) = &self.tracestate {
+			obj.insert(TRACESTATE.to_string(), Value::String(tracestate.clone()));
+		}
+		true
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	const FX_TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+	#[test]
+	fn test_trace_context_from_traceparent() {
+		// -- Exec
+		let trace_context = TraceContext::from_traceparent(FX_TRACEPARENT);
+
+		// -- Check
+		let trace_context = trace_context.expect("should be valid");
+		assert_eq!(trace_context.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+		assert_eq!(trace_context.parent_id(), "00f067aa0ba902b7");
+		assert!(trace_context.is_sampled());
+	}
+
+	#[test]
+	fn test_trace_context_from_traceparent_invalid() {
+		let invalids = [
+			"",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+			"ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+			"00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+			"00-4bf92f3577b34da6a3ce929d0e0e473x-00f067aa0ba902b7-01",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+			"00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00F067AA0BA902B7-01",
+		];
+
+		for invalid in invalids {
+			assert!(
+				TraceContext::from_traceparent(invalid).is_none(),
+				"should be invalid: {invalid}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_trace_context_inject_extract_params_meta() {
+		// -- Setup & Fixtures
+		let trace_context = TraceContext::from_traceparent(FX_TRACEPARENT)
+			.expect("should be valid")
+			.with_tracestate("congo=t61rcWkgMzE");
+		let mut value = json!({"jsonrpc": "2.0", "id": 1, "method": "get_task", "params": {"id": 123}});
+		let mut no_params_value = json!({"jsonrpc": "2.0", "id": 1, "method": "get_task"});
+		let mut null_params_value = json!({"jsonrpc": "2.0", "id": 1, "method": "get_task", "params": null});
+
+		// -- Exec
+		let injected = trace_context.inject(&mut value, TraceContextLocation::ParamsMeta);
+		let extracted = TraceContext::extract(&value, TraceContextLocation::ParamsMeta);
+		let no_params_injected = trace_context.inject(&mut no_params_value, TraceContextLocation::ParamsMeta);
+		let null_params_injected = trace_context.inject(&mut null_params_value, TraceContextLocation::ParamsMeta);
+
+		// -- Check
+		assert!(injected);
+		assert_eq!(value["params"]["_meta"]["traceparent"], json!(FX_TRACEPARENT));
+		assert_eq!(extracted, Some(trace_context));
+		assert!(!no_params_injected);
+		assert_eq!(no_params_value.get("params"), None);
+		assert!(!null_params_injected);
+		assert_eq!(null_params_value["params"], Value::Null);
+	}
+
+	#[test]
+	fn test_trace_context_take_params_meta() {
+		// -- Setup & Fixtures
+		let mut value = json!({"jsonrpc": "2.0", "id": 1, "method": "get_task", "params": {
+			"_meta": {"traceparent": FX_TRACEPARENT, "tracestate": "congo=t61rcWkgMzE"},
+			"id": 123
+		}});
+		let mut other_meta_value = json!({"jsonrpc": "2.0", "id": 1, "method": "get_task", "params": {
+			"_meta": {"traceparent": FX_TRACEPARENT, "progressToken": 1},
+		}});
+
+		// -- Exec
+		let taken = TraceContext::take(&mut value, TraceContextLocation::ParamsMeta);
+		let other_meta_taken = TraceContext::take(&mut other_meta_value, TraceContextLocation::ParamsMeta);
+
+		// -- Check
+		assert!(taken.is_some());
+		assert_eq!(value["params"], json!({"id": 123}));
+		assert!(other_meta_taken.is_some());
+		assert_eq!(other_meta_value["params"], json!({"_meta": {"progressToken": 1}}));
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,135 @@
+use crate::rpc_response::RpcResponse;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// Represents a JSON-RPC 2.0 Batch response, which is the array of `RpcResponse`
+/// for the requests of a batch (notifications do not get a response).
+/// <https://www.jsonrpc.org/specification#batch>
+///
+/// Note: The responses are in the order of the batch requests, although the spec does not require it.
+///       Clients should correlate responses with their requests by `id`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RpcBatchResponse {
+	responses: Vec<RpcResponse>,
+}
+
+// region:    --- Constructors
+
+impl RpcBatchResponse {
+	pub fn new(responses: Vec<RpcResponse>) -> Self {
+		Self { responses }
+	}
+}
+
+// endregion: --- Constructors
+
+// region:    --- Accessors
+
+impl RpcBatchResponse {
+	pub fn responses(&self) -> &[RpcResponse] {
+		&self.responses
+	}
+
+	pub fn into_responses(self) -> Vec<RpcResponse> {
+		self.responses
+	}
+
+	pub fn len(&self) -> usize {
+		self.responses.len()
+	}
+
+	/// Returns true when there is no response to send back (e.g., the batch had only notifications).
+	///
+	/// Note: Per the spec, the server must not return an empty array in this case, but nothing at all.
+	pub fn is_empty(&self) -> bool {
+		self.responses.is_empty()
+	}
+}
+
+impl IntoIterator for RpcBatchResponse {
+	type Item = RpcResponse;
+	type IntoIter = std::vec::IntoIter<RpcResponse>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.responses.into_iter()
+	}
+}
+
+impl RpcBatchResponse {
+	/// Converts the batch into its JSON-RPC array `serde_json::Value` wire form. Infallible.
+	pub fn to_value(&self) -> Value {
+		Value::Array(self.responses.iter().map(RpcResponse::to_value).collect())
+	}
+}
+
+// endregion: --- Accessors
+
+// region:    --- Froms
+
+impl From<Vec<RpcResponse>> for RpcBatchResponse {
+	fn from(responses: Vec<RpcResponse>) -> Self {
+		Self { responses }
+	}
+}
+
+// endregion: --- Froms
+
+// region:    --- Serde Impls
+
+impl Serialize for RpcBatchResponse {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.responses.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for RpcBatchResponse {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let responses = Vec::<RpcResponse>::deserialize(deserializer)?;
+		Ok(Self { responses })
+	}
+}
+
+// endregion: --- Serde Impls
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{RpcError, RpcId};
+	use serde_json::{from_value, json, to_value};
+
+	type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	#[test]
+	fn test_rpc_batch_response_ser_de() -> TestResult<()> {
+		// -- Setup & Fixtures
+		let batch_response = RpcBatchResponse::new(vec![
+			RpcResponse::from_success(RpcId::Number(1), json!(7)),
+			RpcResponse::from_error(RpcId::Null, RpcError::from_invalid_request(None)),
+		]);
+		let expected_json = json!([
+			{"jsonrpc": "2.0", "id": 1, "result": 7},
+			{"jsonrpc": "2.0", "id": null, "error": {"code": -32600, "message": "Invalid Request"}}
+		]);
+
+		// -- Exec
+		let serialized_value = to_value(&batch_response)?;
+		let deserialized: RpcBatchResponse = from_value(serialized_value.clone())?;
+
+		// -- Check
+		assert_eq!(serialized_value, expected_json);
+		assert_eq!(deserialized, batch_response);
+		assert_eq!(batch_response.to_value(), expected_json);
+
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -2,11 +2,13 @@
 
 // region:    --- Modules
 
+mod batch_response;
 mod response;
 mod rpc_error;
 mod rpc_response_parsing_error;
 
 // -- Flatten
+pub use batch_response::*;
 pub use response::*;
 pub use rpc_error::*;
 pub use rpc_response_parsing_error::*;
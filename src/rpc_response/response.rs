@@ -81,6 +81,18 @@ impl RpcResponse {
 }
 // endregion: --- Accessors
 
+// region:    --- Conversions
+
+impl RpcResponse {
+	/// Converts the `RpcResponse` into its JSON-RPC `serde_json::Value` wire form (same as its `Serialize`).
+	pub fn to_value(&self) -> Value {
+		// Note: Infallible, as the response only holds JSON values (the `Null` fallback is never returned).
+		serde_json::to_value(self).unwrap_or_default()
+	}
+}
+
+// endregion: --- Conversions
+
 // region:    --- From Router CallResult/CallSuccess/CallError
 
 impl From<CallSuccess> for RpcResponse {
@@ -261,7 +273,7 @@ mod tests {
 	fn create_call_error(id: impl Into<RpcId>, method: &str, error: RouterError) -> CallError {
 		CallError {
 			id: id.into(),
-			method: method.to_string(),
+			method: method.into(),
 			error,
 		}
 	}
@@ -411,13 +423,30 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_rpc_response_to_value() -> TestResult<()> {
+		// -- Setup & Fixtures
+		let responses = [
+			RpcResponse::from_success(RpcId::Number(1), json!({"data": "ok"})),
+			RpcResponse::from_error(RpcId::Null, RpcError::from_parse_error(None)),
+			RpcResponse::from_error("id-1".into(), RpcError::from_invalid_params(Some(json!("bad")))),
+		];
+
+		// -- Exec & Check
+		for response in responses {
+			assert_eq!(response.to_value(), to_value(&response)?);
+		}
+
+		Ok(())
+	}
+
 	// region:    --- From Router Call Tests
 	#[test]
 	fn test_from_call_success() -> TestResult<()> {
 		// -- Setup & Fixtures
 		let call_success = CallSuccess {
 			id: RpcId::Number(101),
-			method: "test_method".to_string(),
+			method: "test_method".into(),
 			value: json!({"success": true}),
 		};
 
@@ -461,7 +490,7 @@ mod tests {
 		// -- Setup & Fixtures
 		let call_result: CallResult = Ok(CallSuccess {
 			id: 103.into(),
-			method: "test_method".to_string(),
+			method: "test_method".into(),
 			value: json!("ok_data"),
 		});
 
@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{Error, GuardRejection, RpcRequestParsingError};
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 
@@ -27,6 +27,16 @@ impl RpcError {
 	pub const CODE_INVALID_PARAMS: i64 = -32602;
 	pub const CODE_INTERNAL_ERROR: i64 = -32603;
 	// -32000 to -32099: Server error. Reserved for implementation-defined server-errors.
+	/// Server error: The call did not complete within the router timeout (`Error::Timeout`).
+	pub const CODE_TIMEOUT: i64 = -32001;
+	/// Server error: The method concurrency limit is reached (`Error::ConcurrencyLimitReached`).
+	pub const CODE_CONCURRENCY_LIMIT_REACHED: i64 = -32002;
+	/// Server error: The call exceeded its `RateLimit` (`Error::RateLimited`).
+	pub const CODE_RATE_LIMITED: i64 = -32003;
+	/// Server error: The call was rejected by a `Guard` as unauthenticated (`GuardRejection::Unauthorized`).
+	pub const CODE_UNAUTHORIZED: i64 = -32004;
+	/// Server error: The call was rejected by a `Guard` as not allowed (`GuardRejection::Forbidden`).
+	pub const CODE_FORBIDDEN: i64 = -32005;
 
 	pub fn from_parse_error(data: Option<Value>) -> Self {
 		Self {
@@ -68,6 +78,12 @@ impl RpcError {
 		}
 	}
 
+	/// Converts the `RpcError` into its JSON-RPC error object `serde_json::Value` (same as its `Serialize`).
+	pub fn to_value(&self) -> Value {
+		// Note: Infallible, as the error only holds JSON values (the `Null` fallback is never returned).
+		serde_json::to_value(self).unwrap_or_default()
+	}
+
 	/// Helper to create an RpcError with optional data representing the original error string.
 	fn new(code: i64, message: impl Into<String>, error: Option<&dyn std::error::Error>) -> Self {
 		let data = error.map(|e| json!(e.to_string()));
@@ -89,6 +105,17 @@ impl From<&Error> for RpcError {
 			Error::ParamsParsing(p) => Self::new(Self::CODE_INVALID_PARAMS, "Invalid params", Some(p)),
 			Error::ParamsMissingButRequested => Self::new(Self::CODE_INVALID_PARAMS, "Invalid params", Some(err)),
 			Error::MethodUnknown => Self::new(Self::CODE_METHOD_NOT_FOUND, "Method not found", Some(err)),
+			Error::Timeout(_) => Self::new(Self::CODE_TIMEOUT, "Timeout", Some(err)),
+			Error::RateLimited(_) => Self::new(Self::CODE_RATE_LIMITED, "Rate limited", Some(err)),
+			Error::GuardRejected(rejection @ GuardRejection::Unauthorized(_)) => {
+				Self::new(Self::CODE_UNAUTHORIZED, "Unauthorized", Some(rejection))
+			}
+			Error::GuardRejected(rejection @ GuardRejection::Forbidden(_)) => {
+				Self::new(Self::CODE_FORBIDDEN, "Forbidden", Some(rejection))
+			}
+			Error::ConcurrencyLimitReached(_) => {
+				Self::new(Self::CODE_CONCURRENCY_LIMIT_REACHED, "Server busy", Some(err))
+			}
 			Error::FromResources(fr_err) => Self::new(Self::CODE_INTERNAL_ERROR, "Internal error", Some(fr_err)),
 			Error::HandlerResultSerialize(s_err) => Self::new(Self::CODE_INTERNAL_ERROR, "Internal error", Some(s_err)),
 			// NOTE: For HandlerError, we use a generic Internal Error.
@@ -120,3 +147,26 @@ impl From<&crate::CallError> for RpcError {
 }
 
 // endregion: --- From CallError
+
+// region:    --- From RpcRequestParsingError
+
+impl From<&RpcRequestParsingError> for RpcError {
+	/// Converts a request parsing error into a JSON-RPC `RpcError`.
+	///
+	/// - `Parse` (invalid JSON) maps to `-32700 Parse error`.
+	/// - All other variants (valid JSON but not a valid Request object) map to `-32600 Invalid Request`.
+	fn from(err: &RpcRequestParsingError) -> Self {
+		match err {
+			RpcRequestParsingError::Parse(p) => Self::new(Self::CODE_PARSE_ERROR, "Parse error", Some(p)),
+			_ => Self::new(Self::CODE_INVALID_REQUEST, "Invalid Request", Some(err)),
+		}
+	}
+}
+
+impl From<RpcRequestParsingError> for RpcError {
+	fn from(err: RpcRequestParsingError) -> Self {
+		RpcError::from(&err)
+	}
+}
+
+// endregion: --- From RpcRequestParsingError
@@ -0,0 +1,119 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{
+	HandlerResult, RpcBatchRequest, RpcError, RpcId, RpcParams, RpcRequestParsingError, RpcResource, router_builder,
+};
+use serde::Deserialize;
+use serde_json::{json, to_value};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// region:    --- Test Assets
+
+#[derive(Clone, RpcResource)]
+pub struct Counter(Arc<AtomicUsize>);
+
+#[derive(Deserialize, RpcParams)]
+pub struct ParamsIded {
+	pub id: i64,
+}
+
+pub async fn get_task(params: ParamsIded) -> HandlerResult<i64> {
+	Ok(params.id + 9000)
+}
+
+pub async fn incr(counter: Counter) -> HandlerResult<usize> {
+	Ok(counter.0.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_batch_mixed() -> Result<()> {
+	// -- Setup & Fixtures
+	let counter = Counter(Arc::new(AtomicUsize::new(0)));
+	let rpc_router = router_builder!(
+		handlers: [get_task, incr],
+		resources: [counter.clone()]
+	)
+	.build();
+	let batch: RpcBatchRequest = json!([
+		{"jsonrpc": "2.0", "id": 1, "method": "get_task", "params": {"id": 1}},
+		{"jsonrpc": "2.0", "method": "incr"},
+		{"jsonrpc": "2.0", "id": "b", "method": "unknown_method"},
+		{"foo": "boo"},
+		{"jsonrpc": "2.0", "id": 3, "method": "get_task", "params": {"id": 3}}
+	])
+	.try_into()?;
+
+	// -- Exec
+	let batch_response = rpc_router.call_batch(batch).await.ok_or("Should have a batch response")?;
+
+	// -- Check
+	assert_eq!(
+		counter.0.load(Ordering::SeqCst),
+		1,
+		"notification should have been executed"
+	);
+	let responses = batch_response.responses();
+	assert_eq!(responses.len(), 4, "notification should not have a response");
+	assert_eq!(
+		to_value(&responses[0])?,
+		json!({"jsonrpc": "2.0", "id": 1, "result": 9001})
+	);
+	assert_eq!(responses[1].id(), &RpcId::from("b"));
+	let (_, res) = responses[1].clone().into_parts();
+	assert_eq!(res.err().map(|e| e.code), Some(RpcError::CODE_METHOD_NOT_FOUND));
+	let (id, res) = responses[2].clone().into_parts();
+	assert_eq!(id, RpcId::Null);
+	assert_eq!(res.err().map(|e| e.code), Some(RpcError::CODE_INVALID_REQUEST));
+	assert_eq!(
+		to_value(&responses[3])?,
+		json!({"jsonrpc": "2.0", "id": 3, "result": 9003})
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_all_notifications() -> Result<()> {
+	// -- Setup & Fixtures
+	let counter = Counter(Arc::new(AtomicUsize::new(0)));
+	let rpc_router = router_builder!(
+		handlers: [incr],
+		resources: [counter.clone()]
+	)
+	.build();
+	let batch: RpcBatchRequest = json!([
+		{"jsonrpc": "2.0", "method": "incr"},
+		{"jsonrpc": "2.0", "method": "incr"}
+	])
+	.try_into()?;
+
+	// -- Exec
+	let batch_response = rpc_router.call_batch(batch).await;
+
+	// -- Check
+	assert!(
+		batch_response.is_none(),
+		"should not respond to a batch of notifications"
+	);
+	assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_empty() -> Result<()> {
+	// -- Exec
+	let Err(err) = RpcBatchRequest::from_value(json!([])) else {
+		return Err("Empty batch should fail".into());
+	};
+
+	// -- Check
+	assert!(matches!(err, RpcRequestParsingError::BatchEmpty));
+	assert_eq!(RpcError::from(&err).code, RpcError::CODE_INVALID_REQUEST);
+
+	Ok(())
+}
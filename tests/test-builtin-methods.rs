@@ -0,0 +1,71 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, ReloadableRouter, Router};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_builtin_ping_and_methods() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("list_tasks", list_tasks)
+		.rpc_ping()
+		.rpc_methods()
+		.build();
+
+	// -- Exec
+	let ping_res = rpc_router.call_route(None, "rpc.ping", None).await?;
+	let methods_res = rpc_router.call_route(None, "rpc.methods", None).await?;
+
+	// -- Check
+	assert_eq!(ping_res.value, json!("pong"));
+	assert_eq!(methods_res.value, json!(["list_tasks", "rpc.ping", "rpc.methods"]));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_builtin_reserved_and_reload() -> Result<()> {
+	// -- Setup & Fixtures
+	let reserved_res = Router::builder().append("rpc.custom", list_tasks).try_build();
+	let reloadable_router = ReloadableRouter::new(Router::builder().rpc_methods().try_build()?);
+
+	// -- Exec
+	reloadable_router.try_update(|builder| builder.append("list_tasks", list_tasks))?;
+	let methods_res = reloadable_router.router().call_route(None, "rpc.methods", None).await?;
+
+	// -- Check
+	let build_error = reserved_res.err().ok_or("rpc.custom should be reserved")?;
+	assert_eq!(build_error.reserved_methods[0].as_ref(), "rpc.custom");
+	// the built-in method is re-registered on the updated route table
+	assert_eq!(methods_res.value, json!(["list_tasks", "rpc.methods"]));
+
+	Ok(())
+}
+
+#[cfg(feature = "schemars")]
+#[tokio::test]
+async fn test_builtin_discover() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append_with_schema("list_tasks", list_tasks)
+		.rpc_discover(rpc_router::OpenRpcInfo::new("Task API", "1.0.0"))
+		.build();
+
+	// -- Exec
+	let discover_res = rpc_router.call_route(None, "rpc.discover", None).await?;
+
+	// -- Check
+	assert_eq!(discover_res.value["info"]["title"], json!("Task API"));
+	assert_eq!(discover_res.value["methods"][0]["name"], json!("list_tasks"));
+
+	Ok(())
+}
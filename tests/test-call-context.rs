@@ -0,0 +1,83 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{
+	CallContext, FromResources, GuardCall, GuardRejection, HandlerResult, NotificationErrorPolicy, Resources, Router,
+	RpcId, RpcNotification, RpcRequest,
+};
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+
+// region:    --- Test Assets
+
+#[derive(Clone)]
+pub struct UserId(pub i64);
+impl FromResources for UserId {}
+
+pub async fn whoami(ctx: CallContext, user_id: Option<UserId>) -> HandlerResult<Value> {
+	Ok(json!({
+		"id": ctx.id.to_value(),
+		"method": &*ctx.method,
+		"notification": ctx.notification,
+		"user_id": user_id.map(|user_id| user_id.0),
+	}))
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_call_context_in_handler() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("whoami", whoami).build();
+	let additional_resources = Resources::builder().append(UserId(123)).build();
+
+	// -- Exec
+	let res = rpc_router
+		.call_with_resources(RpcRequest::new("req-1", "whoami", None), additional_resources)
+		.await?;
+
+	// -- Check
+	assert_eq!(
+		res.value,
+		json!({"id": "req-1", "method": "whoami", "notification": false, "user_id": 123})
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_call_context_in_guard_and_notification() -> Result<()> {
+	// -- Setup & Fixtures
+	let guarded: Arc<Mutex<Vec<(RpcId, bool)>>> = Default::default();
+	let guard_guarded = guarded.clone();
+	let rpc_router = Router::builder()
+		.append("whoami", whoami)
+		.guard(move |call: &GuardCall| -> core::result::Result<(), GuardRejection> {
+			let ctx = call
+				.resources
+				.get::<CallContext>()
+				.ok_or(GuardRejection::forbidden("no call context"))?;
+			guard_guarded.lock().unwrap().push((ctx.id, ctx.notification));
+			Ok(())
+		})
+		.notification_error_policy(NotificationErrorPolicy::hook(|call_error| {
+			panic!("notification should not fail: {call_error}");
+		}))
+		.build();
+
+	// -- Exec
+	rpc_router.call_route(Some(7.into()), "whoami", None).await?;
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "whoami"}),
+		)?)
+		.await;
+
+	// -- Check
+	assert_eq!(
+		guarded.lock().unwrap().as_slice(),
+		[(RpcId::Number(7), false), (RpcId::Null, true)]
+	);
+
+	Ok(())
+}
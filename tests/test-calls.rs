@@ -135,3 +135,48 @@ async fn test_shared_resources() -> Result<()> {
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn test_runtime_method_names() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_prefix = "v2"; // e.g., from config
+	let rpc_router = Router::builder()
+		.append_dyn(format!("{fx_prefix}_get_task"), get_task.into_dyn())
+		.append_resource(ModelManager)
+		.build();
+
+	// -- Exec
+	let res = rpc_router.call_route(None, "v2_get_task", Some(json!({"id": 126}))).await?;
+
+	// -- Check
+	let res_value: i32 = serde_json::from_value(res.value)?;
+	assert_eq!(res_value, 9126);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_call_method_shared_with_route() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append_dyn("get_task", get_task.into_dyn())
+		.append_resource(ModelManager)
+		.build();
+
+	// -- Exec
+	let res_1 = rpc_router.call_route(None, "get_task", Some(json!({"id": 1}))).await?;
+	let res_2 = rpc_router.call_route(None, "get_task", Some(json!({"id": 2}))).await?;
+	let err = rpc_router
+		.call_route(None, "unknown", None)
+		.await
+		.err()
+		.ok_or("should be err")?;
+
+	// -- Check
+	// The method name of the calls is the one of the route table (not allocated per call).
+	assert!(std::sync::Arc::ptr_eq(&res_1.method, &res_2.method));
+	assert_eq!(&*res_1.method, "get_task");
+	assert_eq!(&*err.method, "unknown");
+
+	Ok(())
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "tokio")]
+
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, ConcurrencyLimit, HandlerResult, Router};
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+// region:    --- Test Assets
+
+#[derive(Clone, Default, rpc_router::RpcResource)]
+pub struct Counters {
+	running: Arc<AtomicUsize>,
+	max_running: Arc<AtomicUsize>,
+}
+
+pub async fn generate_report(counters: Counters) -> HandlerResult<&'static str> {
+	let running = counters.running.fetch_add(1, Ordering::SeqCst) + 1;
+	counters.max_running.fetch_max(running, Ordering::SeqCst);
+	tokio::time::sleep(Duration::from_millis(30)).await;
+	counters.running.fetch_sub(1, Ordering::SeqCst);
+	Ok("report")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_concurrency_limit_queue() -> Result<()> {
+	// -- Setup & Fixtures
+	let counters = Counters::default();
+	let rpc_router = Router::builder()
+		.append_resource(counters.clone())
+		.append("generate_report", generate_report)
+		.method_concurrency_limit("generate_report", ConcurrencyLimit::queue(2))
+		.build();
+
+	// -- Exec
+	let calls = (0..5).map(|_| rpc_router.call_route(None, "generate_report", None));
+	let results = futures::future::join_all(calls).await;
+
+	// -- Check
+	for res in results {
+		assert_eq!(res?.value, json!("report"));
+	}
+	assert_eq!(counters.max_running.load(Ordering::SeqCst), 2);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrency_limit_reject() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append_resource(Counters::default())
+		.append("generate_report", generate_report)
+		.method_concurrency_limit("generate_report", ConcurrencyLimit::reject(1))
+		.build();
+
+	// -- Exec
+	let (first_res, second_res) = tokio::join!(
+		rpc_router.call_route(None, "generate_report", None),
+		rpc_router.call_route(None, "generate_report", None)
+	);
+
+	// -- Check
+	assert_eq!(first_res?.value, json!("report"));
+	assert!(matches!(
+		second_res,
+		Err(CallError {
+			error: rpc_router::Error::ConcurrencyLimitReached(1),
+			..
+		})
+	));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrency_limit_clamped() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append_resource(Counters::default())
+		.append("generate_report", generate_report)
+		.method_concurrency_limit("generate_report", ConcurrencyLimit::queue(0))
+		.build();
+
+	// -- Exec
+	let unbounded = ConcurrencyLimit::reject(usize::MAX);
+	let res = tokio::time::timeout(
+		Duration::from_secs(1),
+		rpc_router.call_route(None, "generate_report", None),
+	)
+	.await?;
+
+	// -- Check
+	assert_eq!(unbounded.max(), tokio::sync::Semaphore::MAX_PERMITS);
+	assert_eq!(ConcurrencyLimit::queue(0).max(), 1);
+	assert_eq!(res?.value, json!("report"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrency_limit_method_unknown() -> Result<()> {
+	// -- Setup & Fixtures
+	let builder = Router::builder()
+		.method_concurrency_limit("generate_report", ConcurrencyLimit::queue(2))
+		.append("generate_report", generate_report);
+
+	// -- Exec
+	let build_error = builder.try_build().err().ok_or("try_build should fail")?;
+
+	// -- Check
+	assert_eq!(build_error.unknown_methods[0].as_ref(), "generate_report");
+
+	Ok(())
+}
@@ -0,0 +1,66 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{FallbackMethod, HandlerResult, Router, RpcNotification};
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+
+// region:    --- Test Assets
+
+pub async fn get_task() -> HandlerResult<&'static str> {
+	Ok("task")
+}
+
+pub async fn legacy_fallback(method: FallbackMethod, params: Option<Value>) -> HandlerResult<Value> {
+	Ok(json!({"legacy_method": method.as_str(), "params": params}))
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_fallback_unknown_method() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("get_task", get_task).fallback(legacy_fallback).build();
+
+	// -- Exec
+	let task_res = rpc_router.call_route(None, "get_task", None).await?;
+	let legacy_res = rpc_router.call_route(None, "legacy_get_task", Some(json!({"id": 123}))).await?;
+
+	// -- Check
+	assert_eq!(task_res.value, json!("task"));
+	assert_eq!(&*legacy_res.method, "legacy_get_task");
+	assert_eq!(
+		legacy_res.value,
+		json!({"legacy_method": "legacy_get_task", "params": {"id": 123}})
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_fallback_notification() -> Result<()> {
+	// -- Setup & Fixtures
+	let methods: Arc<Mutex<Vec<String>>> = Default::default();
+	let fallback_methods = methods.clone();
+	let rpc_router = Router::builder()
+		.fallback(move |method: FallbackMethod| {
+			let fallback_methods = fallback_methods.clone();
+			async move {
+				fallback_methods.lock().unwrap().push(method.0.to_string());
+				HandlerResult::Ok(())
+			}
+		})
+		.build();
+
+	// -- Exec
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "ping"}),
+		)?)
+		.await;
+
+	// -- Check
+	assert_eq!(methods.lock().unwrap().as_slice(), ["ping".to_string()]);
+
+	Ok(())
+}
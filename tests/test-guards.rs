@@ -0,0 +1,175 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{
+	CallError, GuardCall, GuardRejection, HandlerResult, ReloadableRouter, Resources, RouteMeta, Router, RpcError,
+	RpcRequest, RpcResponse,
+};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Clone)]
+pub struct Ctx {
+	scopes: Vec<&'static str>,
+}
+
+/// The scope required to call the route (route metadata).
+#[derive(Clone)]
+pub struct Scope(&'static str);
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+pub async fn list_tasks_v2() -> HandlerResult<&'static str> {
+	Ok("tasks v2")
+}
+
+fn require_ctx(call: &GuardCall) -> core::result::Result<(), GuardRejection> {
+	match call.resources.get::<Ctx>() {
+		Some(_) => Ok(()),
+		None => Err(GuardRejection::unauthorized("No Ctx")),
+	}
+}
+
+fn require_scope(call: &GuardCall) -> core::result::Result<(), GuardRejection> {
+	let (Some(ctx), Some(Scope(scope))) = (call.resources.get::<Ctx>(), call.meta.get::<Scope>()) else {
+		return Ok(());
+	};
+	if ctx.scopes.contains(scope) {
+		Ok(())
+	} else {
+		Err(GuardRejection::forbidden(format!("Missing scope '{scope}'")))
+	}
+}
+
+fn error_code(res: core::result::Result<rpc_router::CallSuccess, CallError>) -> Option<i64> {
+	match RpcResponse::from(res) {
+		RpcResponse::Error(error_response) => Some(error_response.error.code),
+		RpcResponse::Success(_) => None,
+	}
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_guards_router_and_meta() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.guard(require_ctx)
+		.guard(require_scope)
+		.append_with_meta("list_tasks", list_tasks, RouteMeta::new().with(Scope("task:read")))
+		.build();
+	let request = || RpcRequest::new(1, "list_tasks", None);
+	let reader = Resources::builder()
+		.append(Ctx {
+			scopes: vec!["task:read"],
+		})
+		.build();
+	let guest = Resources::builder().append(Ctx { scopes: vec![] }).build();
+
+	// -- Exec
+	let anonymous_res = rpc_router.call(request()).await;
+	let guest_res = rpc_router.call_with_resources(request(), guest).await;
+	let reader_res = rpc_router.call_with_resources(request(), reader).await?;
+
+	// -- Check
+	assert_eq!(error_code(anonymous_res), Some(RpcError::CODE_UNAUTHORIZED));
+	assert_eq!(error_code(guest_res), Some(RpcError::CODE_FORBIDDEN));
+	assert_eq!(reader_res.value, json!("tasks"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_guards_method_and_nest() -> Result<()> {
+	// -- Setup & Fixtures
+	let deny_all = |_: &GuardCall| Err(GuardRejection::forbidden("Denied"));
+	let rpc_router = Router::builder()
+		.append("list_tasks", list_tasks)
+		.append("list_projects", list_tasks)
+		.method_guard("list_projects", deny_all)
+		.nest(
+			"admin",
+			Router::builder().guard(deny_all).append("list_tasks", list_tasks),
+		)
+		.build();
+
+	// -- Exec
+	let tasks_res = rpc_router.call_route(None, "list_tasks", None).await;
+	let projects_res = rpc_router.call_route(None, "list_projects", None).await;
+	let admin_res = rpc_router.call_route(None, "admin.list_tasks", None).await;
+
+	// -- Check
+	assert!(tasks_res.is_ok());
+	assert!(matches!(
+		projects_res,
+		Err(CallError {
+			error: rpc_router::Error::GuardRejected(GuardRejection::Forbidden(_)),
+			..
+		})
+	));
+	assert_eq!(error_code(admin_res), Some(RpcError::CODE_FORBIDDEN));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_guards_kept_on_replace() -> Result<()> {
+	// -- Setup & Fixtures
+	let reloadable_router = ReloadableRouter::new(
+		Router::builder()
+			.nest(
+				"admin",
+				Router::builder().guard(require_ctx).append("list_tasks", list_tasks),
+			)
+			.append("list_projects", list_tasks)
+			.method_guard("list_projects", require_ctx)
+			.build(),
+	);
+	let ctx = || Resources::builder().append(Ctx { scopes: vec![] }).build();
+
+	// -- Exec
+	reloadable_router.update(|builder| {
+		builder
+			.replace("admin.list_tasks", list_tasks_v2)
+			.replace("list_projects", list_tasks_v2)
+	});
+	let router = reloadable_router.router();
+	let admin_res = router.call_route(None, "admin.list_tasks", None).await;
+	let projects_res = router.call_route(None, "list_projects", None).await;
+	let admin_ctx_res = router
+		.call_with_resources(RpcRequest::new(1, "admin.list_tasks", None), ctx())
+		.await?;
+
+	// -- Check
+	assert_eq!(error_code(admin_res), Some(RpcError::CODE_UNAUTHORIZED));
+	assert_eq!(error_code(projects_res), Some(RpcError::CODE_UNAUTHORIZED));
+	assert_eq!(admin_ctx_res.value, json!("tasks v2"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_guards_extend_scoped() -> Result<()> {
+	// -- Setup & Fixtures
+	let admin_builder = Router::builder()
+		.guard(require_ctx)
+		.append("delete_task", list_tasks)
+		.fallback(list_tasks);
+	let rpc_router = Router::builder().append("list_tasks", list_tasks).extend(admin_builder).build();
+
+	// -- Exec
+	let tasks_res = rpc_router.call_route(None, "list_tasks", None).await;
+	let delete_res = rpc_router.call_route(None, "delete_task", None).await;
+	let fallback_res = rpc_router.call_route(None, "anything", None).await;
+
+	// -- Check
+	assert!(tasks_res.is_ok());
+	assert_eq!(error_code(delete_res), Some(RpcError::CODE_UNAUTHORIZED));
+	// the fallback taken from the extended builder keeps its guards
+	assert_eq!(error_code(fallback_res), Some(RpcError::CODE_UNAUTHORIZED));
+
+	Ok(())
+}
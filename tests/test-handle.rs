@@ -0,0 +1,124 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, RpcParams, router_builder};
+use serde::Deserialize;
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Deserialize, RpcParams)]
+pub struct ParamsIded {
+	pub id: i64,
+}
+
+pub async fn get_task(params: ParamsIded) -> HandlerResult<i64> {
+	Ok(params.id + 9000)
+}
+
+pub async fn ping() -> HandlerResult<String> {
+	Ok("pong".to_string())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_handle_value_request() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = router_builder!(get_task, ping).build();
+
+	// -- Exec
+	let res = rpc_router
+		.handle_value(json!({"jsonrpc": "2.0", "id": 1, "method": "get_task", "params": {"id": 123}}))
+		.await;
+
+	// -- Check
+	assert_eq!(res, Some(json!({"jsonrpc": "2.0", "id": 1, "result": 9123})));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_value_notification() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = router_builder!(get_task, ping).build();
+
+	// -- Exec
+	let res = rpc_router.handle_value(json!({"jsonrpc": "2.0", "method": "ping"})).await;
+
+	// -- Check
+	assert_eq!(res, None);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_value_errors() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = router_builder!(get_task, ping).build();
+	let fx_cases = [
+		// (message, expected error code, expected id)
+		(
+			json!({"jsonrpc": "2.0", "id": "a", "method": "nope"}),
+			-32601,
+			json!("a"),
+		),
+		(
+			json!({"jsonrpc": "2.0", "id": 2, "method": "get_task"}),
+			-32602,
+			json!(2),
+		),
+		(
+			json!({"jsonrpc": "2.0", "method": 1, "params": "bar"}),
+			-32600,
+			json!(null),
+		),
+		(json!({"id": 3, "method": "ping"}), -32600, json!(null)),
+		(json!([]), -32600, json!(null)),
+		(json!("foo"), -32600, json!(null)),
+	];
+
+	// -- Exec & Check
+	for (message, code, id) in fx_cases {
+		let res = rpc_router.handle_value(message.clone()).await.ok_or("should respond")?;
+		assert_eq!(res["error"]["code"], json!(code), "error code for {message}");
+		assert_eq!(res["id"], id, "id for {message}");
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_str_batch_and_parse_error() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = router_builder!(get_task, ping).build();
+
+	// -- Exec
+	let batch_res = rpc_router
+		.handle_str(
+			r#"[
+				{"jsonrpc": "2.0", "id": 1, "method": "ping"},
+				{"jsonrpc": "2.0", "method": "ping"},
+				1
+			]"#,
+		)
+		.await;
+	let notifs_res = rpc_router
+		.handle_slice(br#"[{"jsonrpc": "2.0", "method": "ping"}, {"jsonrpc": "2.0", "method": "ping"}]"#)
+		.await;
+	let parse_res = rpc_router.handle_str(r#"{"jsonrpc": "2.0", "method": "ping", "#).await;
+
+	// -- Check
+	let batch_res = batch_res.ok_or("should respond to batch")?;
+	assert_eq!(batch_res[0], json!({"jsonrpc": "2.0", "id": 1, "result": "pong"}));
+	assert_eq!(batch_res[1]["error"]["code"], json!(-32600));
+	assert_eq!(batch_res.as_array().map(|a| a.len()), Some(2));
+
+	assert_eq!(notifs_res, None);
+
+	let parse_res = parse_res.ok_or("should respond to parse error")?;
+	assert_eq!(parse_res["error"]["code"], json!(-32700));
+	assert_eq!(parse_res["id"], json!(null));
+
+	Ok(())
+}
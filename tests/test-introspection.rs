@@ -0,0 +1,69 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, RouteKind, Router};
+
+// region:    --- Test Assets
+
+pub async fn get_task() -> HandlerResult<&'static str> {
+	Ok("task")
+}
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+pub async fn log_event() -> HandlerResult<()> {
+	Ok(())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_introspection_methods_in_order() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("list_tasks", list_tasks)
+		.append_notification("log_event", log_event)
+		.nest("admin", Router::builder().append("get_task", get_task))
+		.append("get_task", get_task)
+		.build();
+
+	// -- Exec
+	let methods = rpc_router.methods();
+	let routes = rpc_router.routes();
+
+	// -- Check
+	assert_eq!(methods, vec!["list_tasks", "admin.get_task", "get_task"]);
+	let route_names: Vec<&str> = routes.iter().map(|route| route.name.as_ref()).collect();
+	assert_eq!(
+		route_names,
+		vec!["list_tasks", "log_event", "admin.get_task", "get_task"]
+	);
+	let orders: Vec<usize> = routes.iter().map(|route| route.order).collect();
+	assert_eq!(orders, vec![0, 1, 2, 3]);
+	assert_eq!(routes[1].kind, RouteKind::Notification);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_introspection_has_method_and_route_info() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("get_task", get_task)
+		.append_notification("log_event", log_event)
+		.build();
+
+	// -- Exec & Check
+	assert!(rpc_router.has_method("get_task"));
+	assert!(!rpc_router.has_method("log_event"));
+	assert!(!rpc_router.has_method("unknown"));
+
+	let info = rpc_router.route_info("log_event").ok_or("should have log_event route")?;
+	assert_eq!(info.kind, RouteKind::Notification);
+	assert_eq!(info.order, 1);
+	assert!(rpc_router.route_info("unknown").is_none());
+
+	Ok(())
+}
@@ -0,0 +1,162 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, HandlerResult, Layer, LayerCall, LayerFuture, Next, Router, RpcNotification, RpcResource};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+// region:    --- Test Assets
+
+type Events = Arc<Mutex<Vec<String>>>;
+
+/// Records the call before and after the next layers.
+struct RecordLayer {
+	name: &'static str,
+	events: Events,
+}
+
+impl Layer for RecordLayer {
+	fn call<'a>(&'a self, call: LayerCall, next: Next<'a>) -> LayerFuture<'a> {
+		Box::pin(async move {
+			self.record(format!("{} before {}", self.name, call.method));
+			let res = next.run(call).await;
+			self.record(format!("{} after ok:{}", self.name, res.is_ok()));
+			res
+		})
+	}
+}
+
+impl RecordLayer {
+	fn record(&self, event: String) {
+		if let Ok(mut events) = self.events.lock() {
+			events.push(event);
+		}
+	}
+}
+
+#[derive(Clone, RpcResource)]
+pub struct UserId(i64);
+
+#[derive(Debug)]
+pub struct Unauthorized;
+
+/// Rejects calls without `UserId` resource, before the handler (and params parsing).
+struct AuthLayer;
+
+impl Layer for AuthLayer {
+	fn call<'a>(&'a self, call: LayerCall, next: Next<'a>) -> LayerFuture<'a> {
+		Box::pin(async move {
+			if call.resources.get::<UserId>().is_none() {
+				return Err(CallError {
+					id: call.id,
+					method: call.method,
+					error: rpc_router::Error::Handler(rpc_router::HandlerError::new("Unauthorized")),
+				});
+			}
+			next.run(call).await
+		})
+	}
+}
+
+pub async fn whoami(user_id: UserId) -> HandlerResult<i64> {
+	Ok(user_id.0)
+}
+
+pub async fn ping() -> HandlerResult<String> {
+	Ok("pong".to_string())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_layers_order() -> Result<()> {
+	// -- Setup & Fixtures
+	let events: Events = Default::default();
+	let rpc_router = Router::builder()
+		.append("ping", ping)
+		.layer(RecordLayer {
+			name: "outer",
+			events: events.clone(),
+		})
+		.layer(RecordLayer {
+			name: "inner",
+			events: events.clone(),
+		})
+		.build();
+
+	// -- Exec
+	rpc_router.call_route(None, "ping", None).await?;
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "unknown"}),
+		)?)
+		.await;
+
+	// -- Check
+	let events = events.lock().map_err(|err| err.to_string())?;
+	assert_eq!(
+		*events,
+		vec![
+			"outer before ping",
+			"inner before ping",
+			"inner after ok:true",
+			"outer after ok:true",
+			"outer before unknown",
+			"inner before unknown",
+			"inner after ok:false",
+			"outer after ok:false",
+		]
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_layers_short_circuit() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("whoami", whoami).layer(AuthLayer).build();
+
+	// -- Exec
+	let unauthorized_res = rpc_router.call_route(None, "whoami", None).await;
+	let authorized_res = rpc_router
+		.call_route_with_resources(
+			None,
+			"whoami",
+			None,
+			rpc_router::Resources::builder().append(UserId(7)).build(),
+		)
+		.await?;
+
+	// -- Check
+	let Err(call_error) = unauthorized_res else {
+		return Err("Should have been rejected by the layer".into());
+	};
+	let rpc_router::Error::Handler(handler_error) = call_error.error else {
+		return Err("Should be a handler error".into());
+	};
+	assert_eq!(handler_error.get::<&'static str>(), Some(&"Unauthorized"));
+	assert_eq!(authorized_res.value, json!(7));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_layers_extend_scoped() -> Result<()> {
+	// -- Setup & Fixtures
+	let events: Events = Default::default();
+	let auth_builder = Router::builder().append("whoami", whoami).layer(RecordLayer {
+		name: "auth",
+		events: events.clone(),
+	});
+	let rpc_router = Router::builder().append("ping", ping).extend(auth_builder).build();
+
+	// -- Exec
+	rpc_router.call_route(None, "ping", None).await?;
+	let _ = rpc_router.call_route(None, "whoami", None).await;
+
+	// -- Check
+	let events = events.lock().map_err(|err| err.to_string())?;
+	assert_eq!(*events, vec!["auth before whoami", "auth after ok:false"]);
+
+	Ok(())
+}
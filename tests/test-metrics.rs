@@ -0,0 +1,94 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, IntoParams, METRICS_UNMATCHED_METHOD, Router, RpcNotification};
+use serde::Deserialize;
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Deserialize)]
+pub struct ParamsIded {
+	pub id: i64,
+}
+impl IntoParams for ParamsIded {}
+
+pub async fn get_task(params: ParamsIded) -> HandlerResult<i64> {
+	Ok(params.id)
+}
+
+pub async fn log_event() -> HandlerResult<()> {
+	Ok(())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_metrics_calls_and_errors() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("get_task", get_task)
+		.append_notification("log_event", log_event)
+		.enable_metrics()
+		.build();
+
+	// -- Exec
+	rpc_router.call_route(None, "get_task", Some(json!({"id": 1}))).await?;
+	let _ = rpc_router.call_route(None, "get_task", Some(json!({"wrong": 1}))).await;
+	let _ = rpc_router.call_route(None, "unknown_a", None).await;
+	let _ = rpc_router.call_route(None, "unknown_b", None).await;
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "log_event"}),
+		)?)
+		.await;
+	let metrics = rpc_router.metrics().ok_or("metrics should be enabled")?;
+
+	// -- Check
+	let get_task_metrics = metrics.method("get_task").ok_or("should have get_task metrics")?;
+	assert_eq!(get_task_metrics.calls, 2);
+	assert_eq!(get_task_metrics.errors, 1);
+	assert_eq!(get_task_metrics.errors_by_kind.get("ParamsParsing"), Some(&1));
+	assert_eq!(get_task_metrics.in_flight, 0);
+	assert_eq!(get_task_metrics.latency.count, 2);
+
+	let unmatched_metrics = metrics
+		.method(METRICS_UNMATCHED_METHOD)
+		.ok_or("should have unmatched metrics")?;
+	assert_eq!(unmatched_metrics.calls, 2);
+	assert_eq!(unmatched_metrics.errors_by_kind.get("MethodUnknown"), Some(&2));
+
+	let log_event_metrics = metrics.method("log_event").ok_or("should have log_event metrics")?;
+	assert_eq!(log_event_metrics.calls, 1);
+	assert_eq!(log_event_metrics.errors, 0);
+
+	assert!(metrics.method("unknown_a").is_none());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_metrics_disabled_and_kept_on_rebuild() -> Result<()> {
+	// -- Setup & Fixtures
+	let plain_router = Router::builder().append("get_task", get_task).build();
+	let rpc_router = Router::builder().append("get_task", get_task).enable_metrics().build();
+
+	// -- Exec
+	rpc_router.call_route(None, "get_task", Some(json!({"id": 1}))).await?;
+	let rebuilt_router = rpc_router.to_builder().append("log_event", log_event).build();
+	rebuilt_router.call_route(None, "get_task", Some(json!({"id": 2}))).await?;
+
+	// -- Check
+	assert!(plain_router.metrics().is_none());
+	let metrics = rebuilt_router.metrics().ok_or("metrics should be enabled")?;
+	assert_eq!(
+		metrics.method("get_task").map(|method_metrics| method_metrics.calls),
+		Some(2)
+	);
+	assert!(
+		metrics.method("log_event").is_none(),
+		"never called methods should not be reported"
+	);
+
+	Ok(())
+}
@@ -0,0 +1,90 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, HandlerResult, Layer, LayerCall, LayerFuture, Next, Router, RouterBuilder};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+pub async fn list_projects() -> HandlerResult<&'static str> {
+	Ok("projects")
+}
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+/// Rejects every call.
+struct DenyLayer;
+
+impl Layer for DenyLayer {
+	fn call<'a>(&'a self, call: LayerCall, _next: Next<'a>) -> LayerFuture<'a> {
+		Box::pin(async move {
+			Err(CallError {
+				id: call.id,
+				method: call.method,
+				error: rpc_router::Error::Handler(rpc_router::HandlerError::new("Denied")),
+			})
+		})
+	}
+}
+
+fn project_builder() -> RouterBuilder {
+	Router::builder().append("list", list_projects)
+}
+
+fn task_builder() -> RouterBuilder {
+	Router::builder().append("list", list_tasks)
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_nest_prefixes() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.nest("project", project_builder())
+		.nest_with_separator("task", "/", task_builder())
+		.build();
+
+	// -- Exec
+	let project_res = rpc_router.call_route(None, "project.list", None).await?;
+	let task_res = rpc_router.call_route(None, "task/list", None).await?;
+	let list_res = rpc_router.call_route(None, "list", None).await;
+
+	// -- Check
+	assert_eq!(project_res.value, json!("projects"));
+	assert_eq!(task_res.value, json!("tasks"));
+	assert!(matches!(
+		list_res,
+		Err(CallError {
+			error: rpc_router::Error::MethodUnknown,
+			..
+		})
+	));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_nest_multi_levels_and_scoped_layers() -> Result<()> {
+	// -- Setup & Fixtures
+	let admin_builder = Router::builder().nest("project", project_builder()).layer(DenyLayer);
+	let rpc_router = Router::builder()
+		.nest("admin", admin_builder)
+		.nest("task", task_builder())
+		.build();
+
+	// -- Exec
+	let admin_res = rpc_router.call_route(None, "admin.project.list", None).await;
+	let task_res = rpc_router.call_route(None, "task.list", None).await?;
+
+	// -- Check
+	let Err(call_error) = admin_res else {
+		return Err("admin routes should be denied by the nested layer".into());
+	};
+	assert_eq!(&*call_error.method, "admin.project.list");
+	assert_eq!(task_res.value, json!("tasks"));
+
+	Ok(())
+}
@@ -0,0 +1,92 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, Router, RpcNotification, RpcResource};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+// region:    --- Test Assets
+
+#[derive(Clone, RpcResource)]
+pub struct Counter(Arc<AtomicUsize>);
+
+pub async fn incr(counter: Counter) -> HandlerResult<usize> {
+	Ok(counter.0.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+pub async fn incr_by_ten(counter: Counter) -> HandlerResult<usize> {
+	Ok(counter.0.fetch_add(10, Ordering::SeqCst) + 10)
+}
+
+pub async fn fail() -> core::result::Result<(), String> {
+	Err("always fail".to_string())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_notify_request_and_notification_handlers() -> Result<()> {
+	// -- Setup & Fixtures
+	let counter = Counter(Arc::new(AtomicUsize::new(0)));
+	let rpc_router = Router::builder()
+		.append("incr", incr)
+		.append_notification("incr_notif", incr_by_ten)
+		.append_resource(counter.clone())
+		.build();
+
+	// -- Exec
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "incr"}),
+		)?)
+		.await;
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "incr_notif"}),
+		)?)
+		.await;
+	let call_res = rpc_router.call_route(None, "incr_notif", None).await;
+
+	// -- Check
+	assert_eq!(counter.0.load(Ordering::SeqCst), 11);
+	assert!(
+		matches!(call_res, Err(ref err) if matches!(err.error, rpc_router::Error::MethodUnknown)),
+		"notification-only handler should not be callable as request"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_notify_error_hook() -> Result<()> {
+	// -- Setup & Fixtures
+	let errors: Arc<Mutex<Vec<String>>> = Default::default();
+	let hook_errors = errors.clone();
+	let rpc_router = Router::builder()
+		.append_notification("fail", fail)
+		.on_notification_error(move |call_error| {
+			if let Ok(mut errors) = hook_errors.lock() {
+				errors.push(call_error.method.to_string());
+			}
+		})
+		.build();
+
+	// -- Exec
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "fail"}),
+		)?)
+		.await;
+	rpc_router
+		.notify(RpcNotification::from_value(
+			json!({"jsonrpc": "2.0", "method": "unknown"}),
+		)?)
+		.await;
+
+	// -- Check
+	let errors = errors.lock().map_err(|err| err.to_string())?;
+	assert_eq!(*errors, vec!["fail".to_string(), "unknown".to_string()]);
+
+	Ok(())
+}
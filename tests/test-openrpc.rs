@@ -0,0 +1,89 @@
+#![cfg(feature = "schemars")]
+
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, OpenRpcInfo, Router, RpcParams};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Deserialize, JsonSchema, RpcParams)]
+pub struct ParamsIded {
+	id: i64,
+	/// Optional, not required.
+	#[allow(unused)]
+	with_tags: Option<bool>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct Task {
+	id: i64,
+	title: String,
+	done_at: Option<String>,
+}
+
+pub async fn get_task(params: ParamsIded) -> HandlerResult<Task> {
+	Ok(Task {
+		id: params.id,
+		title: "task".to_string(),
+		done_at: None,
+	})
+}
+
+pub async fn count_tasks() -> HandlerResult<i64> {
+	Ok(0)
+}
+
+pub async fn undocumented() -> HandlerResult<()> {
+	Ok(())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_openrpc_document() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append_with_schema("get_task", get_task)
+		.append_with_schema("count_tasks", count_tasks)
+		.append("undocumented", undocumented)
+		.append_notification("log_event", undocumented)
+		.build();
+
+	// -- Exec
+	let doc = rpc_router.openrpc(&OpenRpcInfo::new("Task API", "1.0.0"));
+
+	// -- Check
+	assert_eq!(doc["openrpc"], json!("1.3.2"));
+	assert_eq!(doc["info"], json!({"title": "Task API", "version": "1.0.0"}));
+	let methods = doc["methods"].as_array().ok_or("methods should be an array")?;
+	let names: Vec<&str> = methods.iter().filter_map(|m| m["name"].as_str()).collect();
+	assert_eq!(names, vec!["get_task", "count_tasks", "undocumented"]);
+
+	// get_task
+	let get_task = &methods[0];
+	assert_eq!(get_task["paramStructure"], json!("by-name"));
+	assert_eq!(get_task["params"][0]["name"], json!("id"));
+	assert_eq!(get_task["params"][0]["required"], json!(true));
+	assert_eq!(get_task["params"][1]["name"], json!("with_tags"));
+	assert_eq!(get_task["params"][1]["required"], json!(false));
+	assert_eq!(get_task["params"][1]["schema"]["type"], json!(["boolean", "null"]));
+	assert_eq!(
+		get_task["result"]["schema"],
+		json!({"$ref": "#/components/schemas/Task"})
+	);
+	let task_schema = &doc["components"]["schemas"]["Task"];
+	assert_eq!(task_schema["type"], json!("object"));
+	assert_eq!(task_schema["properties"]["done_at"]["type"], json!(["string", "null"]));
+	assert!(task_schema["properties"]["done_at"].get("nullable").is_none());
+
+	// count_tasks & undocumented
+	assert_eq!(methods[1]["params"], json!([]));
+	assert_eq!(methods[1]["result"]["schema"]["type"], json!("integer"));
+	assert_eq!(methods[2]["result"], json!({"name": "result", "schema": {}}));
+
+	Ok(())
+}
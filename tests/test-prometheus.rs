@@ -0,0 +1,53 @@
+#![cfg(feature = "prometheus")]
+
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, RPC_METRICS, Router};
+
+// region:    --- Test Assets
+
+pub async fn get_task() -> HandlerResult<&'static str> {
+	Ok("task")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_prometheus_text_exposition() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("get_task", get_task).enable_metrics().build();
+
+	// -- Exec
+	rpc_router.call_route(None, "get_task", None).await?;
+	let _ = rpc_router.call_route(None, "unknown", None).await;
+	let text = rpc_router.metrics().ok_or("metrics should be enabled")?.to_prometheus();
+
+	// -- Check
+	assert!(text.contains("# TYPE rpc_router_calls_total counter\n"));
+	assert!(text.contains("rpc_router_calls_total{method=\"get_task\"} 1\n"));
+	assert!(text.contains("rpc_router_errors_total{method=\"<unmatched>\",kind=\"MethodUnknown\"} 1\n"));
+	assert!(text.contains("rpc_router_in_flight{method=\"get_task\"} 0\n"));
+	assert!(text.contains("# TYPE rpc_router_call_duration_seconds histogram\n"));
+	assert!(text.contains("rpc_router_call_duration_seconds_bucket{method=\"get_task\",le=\"+Inf\"} 1\n"));
+	assert!(text.contains("rpc_router_call_duration_seconds_count{method=\"get_task\"} 1\n"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_prometheus_rpc_metrics_method() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("get_task", get_task).rpc_metrics().build();
+
+	// -- Exec
+	rpc_router.call_route(None, "get_task", None).await?;
+	let res = rpc_router.call_route(None, RPC_METRICS, None).await?;
+
+	// -- Check
+	let text = res.value.as_str().ok_or("rpc.metrics should return a string")?;
+	assert!(text.contains("rpc_router_calls_total{method=\"get_task\"} 1\n"));
+	assert_eq!(rpc_router.methods(), vec!["get_task", RPC_METRICS]);
+
+	Ok(())
+}
@@ -0,0 +1,106 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, HandlerResult, RateLimit, Resources, Router, RpcRequest};
+use serde_json::json;
+use std::time::Duration;
+
+// region:    --- Test Assets
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct UserId(i64);
+
+pub async fn create_task() -> HandlerResult<&'static str> {
+	Ok("created")
+}
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_rate_limit_method_keyed() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("create_task", create_task)
+		.append("list_tasks", list_tasks)
+		.method_layer(
+			"create_task",
+			RateLimit::new(1, Duration::from_secs(60)).keyed_by::<UserId>(),
+		)
+		.build();
+	let user_1 = || Resources::builder().append(UserId(1)).build();
+	let user_2 = || Resources::builder().append(UserId(2)).build();
+	let request = || RpcRequest::new(1, "create_task", None);
+
+	// -- Exec
+	let user_1_first = rpc_router.call_with_resources(request(), user_1()).await;
+	let user_1_second = rpc_router.call_with_resources(request(), user_1()).await;
+	let user_2_first = rpc_router.call_with_resources(request(), user_2()).await;
+	let list_res = rpc_router
+		.call_with_resources(RpcRequest::new(2, "list_tasks", None), user_1())
+		.await;
+
+	// -- Check
+	assert_eq!(user_1_first?.value, json!("created"));
+	assert!(matches!(
+		user_1_second,
+		Err(CallError {
+			error: rpc_router::Error::RateLimited(_),
+			..
+		})
+	));
+	assert_eq!(user_2_first?.value, json!("created"));
+	assert_eq!(list_res?.value, json!("tasks"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_unknown_methods_shared() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.layer(RateLimit::new(10, Duration::from_secs(60)))
+		.append("list_tasks", list_tasks)
+		.build();
+
+	// -- Exec
+	let mut unknown_errors = Vec::new();
+	for idx in 0..1_000 {
+		let res = rpc_router.call(RpcRequest::new(idx, format!("unknown_{idx}"), None)).await;
+		if let Err(call_error) = res {
+			unknown_errors.push(call_error.error);
+		}
+	}
+	let list_res = rpc_router.call(RpcRequest::new(1, "list_tasks", None)).await;
+
+	// -- Check
+	assert_eq!(unknown_errors.len(), 1_000);
+	let rate_limited = unknown_errors
+		.iter()
+		.filter(|error| matches!(error, rpc_router::Error::RateLimited(_)))
+		.count();
+	// the unknown method names share one bucket (10 calls, then rate limited)
+	assert_eq!(rate_limited, 990);
+	assert_eq!(list_res?.value, json!("tasks"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_method_unknown() -> Result<()> {
+	// -- Setup & Fixtures
+	let builder = Router::builder()
+		.append("create_task", create_task)
+		.method_layer("create_tasks", RateLimit::new(1, Duration::from_secs(60)));
+
+	// -- Exec
+	let build_error = builder.try_build().err().ok_or("try_build should fail")?;
+
+	// -- Check
+	assert_eq!(build_error.unknown_methods[0].as_ref(), "create_tasks");
+
+	Ok(())
+}
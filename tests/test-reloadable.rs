@@ -0,0 +1,73 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, HandlerResult, ReloadableRouter, Router, RpcRequest};
+use serde_json::json;
+use std::time::Duration;
+
+// region:    --- Test Assets
+
+pub async fn get_task() -> HandlerResult<&'static str> {
+	Ok("task")
+}
+
+pub async fn slow_task() -> HandlerResult<&'static str> {
+	tokio::time::sleep(Duration::from_millis(50)).await;
+	Ok("slow")
+}
+
+pub async fn plugin_run() -> HandlerResult<&'static str> {
+	Ok("plugin")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_reloadable_update() -> Result<()> {
+	// -- Setup & Fixtures
+	let reloadable_router = ReloadableRouter::new(Router::builder().append("get_task", get_task).build());
+
+	// -- Exec
+	reloadable_router.update(|builder| builder.append("plugin.run", plugin_run).remove("get_task"));
+	let plugin_res = reloadable_router.call(RpcRequest::new(1, "plugin.run", None)).await?;
+	let task_res = reloadable_router.call(RpcRequest::new(2, "get_task", None)).await;
+	let conflict_res = reloadable_router.try_update(|builder| builder.append("plugin.run", get_task));
+
+	// -- Check
+	assert_eq!(plugin_res.value, json!("plugin"));
+	assert!(matches!(
+		task_res,
+		Err(CallError {
+			error: rpc_router::Error::MethodUnknown,
+			..
+		})
+	));
+	let build_error = conflict_res.err().ok_or("try_update should fail")?;
+	assert_eq!(build_error.duplicate_methods[0].as_ref(), "plugin.run");
+	// the conflicting update was not applied
+	assert_eq!(reloadable_router.router().methods(), vec!["plugin.run"]);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_reloadable_in_flight_call() -> Result<()> {
+	// -- Setup & Fixtures
+	let reloadable_router = ReloadableRouter::new(Router::builder().append("slow_task", slow_task).build());
+
+	// -- Exec
+	let in_flight = tokio::spawn({
+		let reloadable_router = reloadable_router.clone();
+		async move { reloadable_router.call(RpcRequest::new(1, "slow_task", None)).await }
+	});
+	tokio::time::sleep(Duration::from_millis(10)).await;
+	let previous_router = reloadable_router.swap(Router::builder().build());
+	let in_flight_res = in_flight.await??;
+
+	// -- Check
+	assert_eq!(in_flight_res.value, json!("slow"));
+	assert!(previous_router.has_method("slow_task"));
+	assert!(!reloadable_router.router().has_method("slow_task"));
+
+	Ok(())
+}
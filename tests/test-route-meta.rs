@@ -0,0 +1,84 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, HandlerError, HandlerResult, Layer, LayerCall, LayerFuture, Next, RouteMeta, Router};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadOnly;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Doc(&'static str);
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+pub async fn delete_task() -> HandlerResult<&'static str> {
+	Ok("deleted")
+}
+
+/// Only allows the read-only routes (e.g., maintenance mode).
+struct ReadOnlyModeLayer;
+
+impl Layer for ReadOnlyModeLayer {
+	fn call<'a>(&'a self, call: LayerCall, next: Next<'a>) -> LayerFuture<'a> {
+		let read_only = next.route_meta(&call).is_some_and(|meta| meta.contains::<ReadOnly>());
+		Box::pin(async move {
+			if read_only {
+				next.run(call).await
+			} else {
+				Err(CallError {
+					id: call.id,
+					method: call.method,
+					error: rpc_router::Error::Handler(HandlerError::new("Read-only mode")),
+				})
+			}
+		})
+	}
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_route_meta_layer() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.layer(ReadOnlyModeLayer)
+		.append_with_meta("list_tasks", list_tasks, RouteMeta::new().with(ReadOnly))
+		.append("delete_task", delete_task)
+		.build();
+
+	// -- Exec
+	let list_res = rpc_router.call_route(None, "list_tasks", None).await?;
+	let delete_res = rpc_router.call_route(None, "delete_task", None).await;
+
+	// -- Check
+	assert_eq!(list_res.value, json!("tasks"));
+	assert!(delete_res.is_err());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_route_meta_introspection() -> Result<()> {
+	// -- Setup & Fixtures
+	let meta = RouteMeta::new().with(ReadOnly).with(Doc("List all the tasks"));
+	let rpc_router = Router::builder()
+		.nest("task", Router::builder().append_with_meta("list", list_tasks, meta))
+		.append("delete_task", delete_task)
+		.build();
+
+	// -- Exec
+	let list_info = rpc_router.route_info("task.list").ok_or("should have task.list")?;
+	let delete_info = rpc_router.route_info("delete_task").ok_or("should have delete_task")?;
+
+	// -- Check
+	assert_eq!(list_info.meta.get::<Doc>(), Some(&Doc("List all the tasks")));
+	assert!(list_info.meta.contains::<ReadOnly>());
+	assert!(delete_info.meta.is_empty());
+
+	Ok(())
+}
@@ -0,0 +1,99 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{FromResources, HandlerResult, Router, static_router_builder};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Clone)]
+pub struct ModelManager;
+impl FromResources for ModelManager {}
+
+pub async fn get_task(_mm: ModelManager) -> HandlerResult<&'static str> {
+	Ok("task")
+}
+
+pub async fn list_tasks() -> HandlerResult<&'static str> {
+	Ok("tasks")
+}
+
+pub async fn delete_task() -> HandlerResult<&'static str> {
+	Ok("deleted")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_static_router_dispatch() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = static_router_builder!(
+		handlers: [get_task, list_tasks],
+		resources: [ModelManager]
+	)
+	.build();
+
+	// -- Exec
+	let get_res = rpc_router.call_route(None, "get_task", None).await?;
+	let list_res = rpc_router.call_route(None, "list_tasks", None).await?;
+	let unknown_res = rpc_router.call_route(None, "delete_task", None).await;
+
+	// -- Check
+	assert_eq!(get_res.value, json!("task"));
+	assert_eq!(list_res.value, json!("tasks"));
+	assert!(matches!(
+		unknown_res.map_err(|err| err.error),
+		Err(rpc_router::Error::MethodUnknown)
+	));
+	assert_eq!(rpc_router.methods(), vec!["get_task", "list_tasks"]);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_static_router_with_dynamic_routes() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = static_router_builder!(list_tasks)
+		.append("delete_task", delete_task)
+		.extend(Router::builder().append("get_task", get_task))
+		.append_resource(ModelManager)
+		.rpc_ping()
+		.build();
+
+	// -- Exec
+	let list_res = rpc_router.call_route(None, "list_tasks", None).await?;
+	let delete_res = rpc_router.call_route(None, "delete_task", None).await?;
+	let get_res = rpc_router.call_route(None, "get_task", None).await?;
+	let ping_res = rpc_router.call_route(None, "rpc.ping", None).await?;
+
+	// -- Check
+	assert_eq!(list_res.value, json!("tasks"));
+	assert_eq!(delete_res.value, json!("deleted"));
+	assert_eq!(get_res.value, json!("task"));
+	assert_eq!(ping_res.value, json!("pong"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_static_router_replace_and_remove() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = static_router_builder!(list_tasks, delete_task)
+		.replace("list_tasks", get_task)
+		.append_resource(ModelManager)
+		.build();
+	let rpc_router = rpc_router.to_builder().remove("delete_task").build();
+
+	// -- Exec
+	let list_res = rpc_router.call_route(None, "list_tasks", None).await?;
+	let delete_res = rpc_router.call_route(None, "delete_task", None).await;
+
+	// -- Check
+	assert_eq!(list_res.value, json!("task"));
+	assert!(matches!(
+		delete_res.map_err(|err| err.error),
+		Err(rpc_router::Error::MethodUnknown)
+	));
+
+	Ok(())
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "tokio")]
+
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CallError, HandlerResult, Router, RpcError, RpcResponse};
+use serde_json::json;
+use std::time::Duration;
+
+// region:    --- Test Assets
+
+pub async fn slow_task() -> HandlerResult<&'static str> {
+	tokio::time::sleep(Duration::from_millis(100)).await;
+	Ok("slow")
+}
+
+pub async fn fast_task() -> HandlerResult<&'static str> {
+	Ok("fast")
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_timeout_default() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("slow_task", slow_task)
+		.append("fast_task", fast_task)
+		.timeout(Duration::from_millis(20))
+		.build();
+
+	// -- Exec
+	let slow_res = rpc_router.call_route(None, "slow_task", None).await;
+	let fast_res = rpc_router.call_route(None, "fast_task", None).await?;
+
+	// -- Check
+	assert_eq!(fast_res.value, json!("fast"));
+	let Err(call_error) = slow_res else {
+		return Err("slow_task should time out".into());
+	};
+	assert!(matches!(call_error.error, rpc_router::Error::Timeout(_)));
+	let RpcResponse::Error(error_response) = RpcResponse::from(call_error) else {
+		return Err("should be an error response".into());
+	};
+	assert_eq!(error_response.error.code, RpcError::CODE_TIMEOUT);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_timeout_method_override() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("slow_task", slow_task)
+		.timeout(Duration::from_millis(20))
+		.method_timeout("slow_task", Duration::from_secs(2))
+		.nest(
+			"sub",
+			Router::builder()
+				.append("slow_task", slow_task)
+				.timeout(Duration::from_millis(10)),
+		)
+		.build();
+
+	// -- Exec
+	let slow_res = rpc_router.call_route(None, "slow_task", None).await?;
+	let sub_slow_res = rpc_router.call_route(None, "sub.slow_task", None).await;
+
+	// -- Check
+	assert_eq!(slow_res.value, json!("slow"));
+	assert!(matches!(
+		sub_slow_res,
+		Err(CallError {
+			error: rpc_router::Error::Timeout(timeout),
+			..
+		}) if timeout == Duration::from_millis(10)
+	));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_timeout_method_unknown() -> Result<()> {
+	// -- Setup & Fixtures
+	let builder = Router::builder()
+		.append("slow_task", slow_task)
+		.method_timeout("slow_tsk", Duration::from_millis(20));
+
+	// -- Exec
+	let build_error = builder.try_build().err().ok_or("try_build should fail")?;
+
+	// -- Check
+	assert_eq!(build_error.unknown_methods[0].as_ref(), "slow_tsk");
+
+	Ok(())
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "tower")]
+
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error + Send + Sync>; // For early dev.
+
+use rpc_router::{HandlerResult, Router, RpcParams, RpcRequest};
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+use tower::{ServiceBuilder, ServiceExt};
+
+// region:    --- Test Assets
+
+#[derive(Deserialize, RpcParams)]
+pub struct ParamsIded {
+	pub id: i64,
+}
+
+pub async fn get_task(params: ParamsIded) -> HandlerResult<i64> {
+	Ok(params.id + 9000)
+}
+
+pub async fn slow() -> HandlerResult<()> {
+	tokio::time::sleep(Duration::from_millis(200)).await;
+	Ok(())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_tower_oneshot() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("get_task", get_task).build();
+
+	// -- Exec
+	let res = rpc_router
+		.oneshot(RpcRequest::new(1, "get_task", Some(json!({"id": 123}))))
+		.await?;
+
+	// -- Check
+	assert_eq!(res.value, json!(9123));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_tower_timeout_layer() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("slow", slow).build();
+	let service = ServiceBuilder::new().timeout(Duration::from_millis(20)).service(rpc_router);
+
+	// -- Exec
+	let res = service.oneshot(RpcRequest::new(1, "slow", None)).await;
+
+	// -- Check
+	let err = res.err().ok_or("Should have timed out")?;
+	assert!(err.is::<tower::timeout::error::Elapsed>());
+
+	Ok(())
+}
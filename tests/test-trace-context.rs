@@ -0,0 +1,138 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{HandlerResult, IntoParams, Router, RpcRequest, TraceContext, TraceContextLocation};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+// region:    --- Test Assets
+
+const FX_TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+pub async fn get_trace(trace_context: Option<TraceContext>) -> HandlerResult<Option<String>> {
+	Ok(trace_context.map(|trace_context| trace_context.trace_id().to_string()))
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParamsIded {
+	pub id: i64,
+}
+impl IntoParams for ParamsIded {}
+
+pub async fn get_task_trace(trace_context: Option<TraceContext>, params: ParamsIded) -> HandlerResult<Value> {
+	let trace_id = trace_context.map(|trace_context| trace_context.trace_id().to_string());
+	Ok(json!({"id": params.id, "trace_id": trace_id}))
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_trace_context_extension() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("get_trace", get_trace)
+		.trace_context(TraceContextLocation::Extension)
+		.build();
+	let request = json!({"jsonrpc": "2.0", "id": 1, "method": "get_trace", "traceparent": FX_TRACEPARENT});
+
+	// -- Exec
+	let res = rpc_router.handle_value(request).await.ok_or("should have response")?;
+
+	// -- Check
+	assert_eq!(res["result"], json!("4bf92f3577b34da6a3ce929d0e0e4736"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_trace_context_params_meta_batch() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("get_trace", get_trace)
+		.trace_context(TraceContextLocation::ParamsMeta)
+		.build();
+	let batch = json!([
+		{"jsonrpc": "2.0", "id": 1, "method": "get_trace", "params": {"_meta": {"traceparent": FX_TRACEPARENT}}},
+		{"jsonrpc": "2.0", "id": 2, "method": "get_trace"},
+		{"jsonrpc": "2.0", "id": 3, "method": "get_trace", "params": {"_meta": {"traceparent": "invalid"}}},
+	]);
+
+	// -- Exec
+	let res = rpc_router.handle_value(batch).await.ok_or("should have response")?;
+
+	// -- Check
+	let results: Vec<&Value> = res
+		.as_array()
+		.ok_or("should be array")?
+		.iter()
+		.map(|res| &res["result"])
+		.collect();
+	assert_eq!(
+		results,
+		vec![&json!("4bf92f3577b34da6a3ce929d0e0e4736"), &Value::Null, &Value::Null]
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_trace_context_params_meta_stripped() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("get_task_trace", get_task_trace)
+		.trace_context(TraceContextLocation::ParamsMeta)
+		.build();
+	let request = json!({"jsonrpc": "2.0", "id": 1, "method": "get_task_trace", "params": {
+		"_meta": {"traceparent": FX_TRACEPARENT},
+		"id": 123
+	}});
+
+	// -- Exec
+	let res = rpc_router.handle_value(request).await.ok_or("should have response")?;
+
+	// -- Check
+	assert_eq!(
+		res["result"],
+		json!({"id": 123, "trace_id": "4bf92f3577b34da6a3ce929d0e0e4736"})
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_trace_context_disabled() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder().append("get_trace", get_trace).build();
+	let request = json!({"jsonrpc": "2.0", "id": 1, "method": "get_trace", "traceparent": FX_TRACEPARENT});
+
+	// -- Exec
+	let res = rpc_router.handle_value(request).await.ok_or("should have response")?;
+
+	// -- Check
+	assert_eq!(res["result"], Value::Null);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_trace_context_client_inject() -> Result<()> {
+	// -- Setup & Fixtures
+	let trace_context = TraceContext::from_traceparent(FX_TRACEPARENT)
+		.ok_or("should be valid")?
+		.with_tracestate("congo=t61rcWkgMzE");
+	let rpc_request = RpcRequest::new(1, "get_trace", None);
+
+	// -- Exec
+	let value = rpc_request.to_value_with_trace_context(&trace_context, TraceContextLocation::Extension);
+	let (parsed_request, parsed_trace_context) =
+		RpcRequest::from_value_with_trace_context(value.clone(), TraceContextLocation::Extension)?;
+
+	// -- Check
+	assert_eq!(value["traceparent"], json!(FX_TRACEPARENT));
+	assert_eq!(value["tracestate"], json!("congo=t61rcWkgMzE"));
+	assert_eq!(parsed_request.method, "get_trace");
+	assert_eq!(parsed_trace_context, Some(trace_context));
+
+	Ok(())
+}
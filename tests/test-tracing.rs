@@ -0,0 +1,114 @@
+#![cfg(feature = "tracing")]
+
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{CALL_SPAN_NAME, HandlerResult, Router};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+// region:    --- Test Assets
+
+pub async fn get_task() -> HandlerResult<&'static str> {
+	tracing::info!("in get_task");
+	Ok("task")
+}
+
+/// Captures the fields of the `rpc.call` spans, and the span of the handler events.
+#[derive(Clone, Default)]
+struct CaptureLayer {
+	span_fields: Arc<Mutex<Vec<(String, String)>>>,
+	event_spans: Arc<Mutex<Vec<String>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldVisitor<'_> {
+	fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+		self.0.push((field.name().to_string(), format!("{value:?}")));
+	}
+
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.0.push((field.name().to_string(), value.to_string()));
+	}
+}
+
+impl<S> Layer<S> for CaptureLayer
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+{
+	fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+		attrs.record(&mut FieldVisitor(&mut self.span_fields.lock().unwrap()));
+	}
+
+	fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+		values.record(&mut FieldVisitor(&mut self.span_fields.lock().unwrap()));
+	}
+
+	fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.event_span(event) {
+			self.event_spans.lock().unwrap().push(span.name().to_string());
+		}
+	}
+}
+
+impl CaptureLayer {
+	fn field(&self, name: &str) -> Option<String> {
+		let span_fields = self.span_fields.lock().unwrap();
+		span_fields
+			.iter()
+			.rev()
+			.find(|(n, _)| n == name)
+			.map(|(_, value)| value.clone())
+	}
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_tracing_call_span() -> Result<()> {
+	// -- Setup & Fixtures
+	let capture = CaptureLayer::default();
+	let subscriber = tracing_subscriber::registry().with(capture.clone());
+	let _guard = tracing::subscriber::set_default(subscriber);
+	let rpc_router = Router::builder().append("get_task", get_task).build();
+
+	// -- Exec
+	rpc_router.call_route(Some(7.into()), "get_task", None).await?;
+
+	// -- Check
+	assert_eq!(capture.field("rpc.id").as_deref(), Some("7"));
+	assert_eq!(capture.field("rpc.method").as_deref(), Some("get_task"));
+	assert_eq!(capture.field("rpc.notification").as_deref(), Some("false"));
+	assert_eq!(capture.field("rpc.outcome").as_deref(), Some("ok"));
+	assert_eq!(
+		capture.event_spans.lock().unwrap().as_slice(),
+		[CALL_SPAN_NAME.to_string()]
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_tracing_call_span_error_kind() -> Result<()> {
+	// -- Setup & Fixtures
+	let capture = CaptureLayer::default();
+	let subscriber = tracing_subscriber::registry().with(capture.clone());
+	let _guard = tracing::subscriber::set_default(subscriber);
+	let rpc_router = Router::builder().append("get_task", get_task).build();
+
+	// -- Exec
+	let _ = rpc_router.call_route(None, "unknown", None).await;
+
+	// -- Check
+	assert_eq!(capture.field("rpc.method").as_deref(), Some("unknown"));
+	assert_eq!(capture.field("rpc.outcome").as_deref(), Some("error"));
+	assert_eq!(capture.field("rpc.error_kind").as_deref(), Some("MethodUnknown"));
+
+	Ok(())
+}
@@ -0,0 +1,94 @@
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+use rpc_router::{GuardCall, GuardRejection, HandlerResult, Resources, Router, RpcResource};
+use serde_json::json;
+
+// region:    --- Test Assets
+
+#[derive(Clone, RpcResource)]
+pub struct ModelManager;
+
+pub async fn get_task_v1() -> HandlerResult<&'static str> {
+	Ok("v1")
+}
+
+pub async fn get_task_v2() -> HandlerResult<&'static str> {
+	Ok("v2")
+}
+
+pub fn allow_all(_call: &GuardCall) -> core::result::Result<(), GuardRejection> {
+	Ok(())
+}
+
+// endregion: --- Test Assets
+
+#[tokio::test]
+async fn test_try_build_duplicates() -> Result<()> {
+	// -- Setup & Fixtures
+	let other_builder = Router::builder()
+		.append("get_task", get_task_v2)
+		.extend_resources(Some(Resources::builder().append(ModelManager)));
+	let builder = Router::builder()
+		.append("get_task", get_task_v1)
+		.append_resource(ModelManager)
+		.extend(other_builder);
+
+	// -- Exec
+	let Err(build_error) = builder.try_build() else {
+		return Err("try_build should fail with duplicates".into());
+	};
+
+	// -- Check
+	assert_eq!(build_error.duplicate_methods.len(), 1);
+	assert_eq!(build_error.duplicate_methods[0].as_ref(), "get_task");
+	assert_eq!(build_error.duplicate_resources.len(), 1);
+	assert!(build_error.duplicate_resources[0].ends_with("ModelManager"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_try_build_replace() -> Result<()> {
+	// -- Setup & Fixtures
+	let rpc_router = Router::builder()
+		.append("get_task", get_task_v1)
+		.append_notification("get_task", get_task_v1)
+		.append_resource(ModelManager)
+		.append_resource(ModelManager)
+		.replace("get_task", get_task_v2)
+		.try_build()?;
+
+	// -- Exec
+	let res = rpc_router.call_route(None, "get_task", None).await?;
+
+	// -- Check
+	assert_eq!(res.value, json!("v2"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_try_build_unknown_methods() -> Result<()> {
+	// -- Setup & Fixtures
+	let admin_builder = Router::builder()
+		.append("delete_task", get_task_v1)
+		.method_guard("delete_tsk", allow_all);
+	let builder = Router::builder()
+		.method_guard("get_task", allow_all)
+		.append("get_task", get_task_v1)
+		.method_guard("get_task", allow_all)
+		.nest("admin", admin_builder);
+
+	// -- Exec
+	let Err(build_error) = builder.try_build() else {
+		return Err("try_build should fail with unknown methods".into());
+	};
+
+	// -- Check
+	let unknown_methods: Vec<&str> = build_error.unknown_methods.iter().map(|name| name.as_ref()).collect();
+	assert_eq!(unknown_methods, vec!["get_task", "admin.delete_tsk"]);
+	assert!(build_error.duplicate_methods.is_empty());
+
+	Ok(())
+}